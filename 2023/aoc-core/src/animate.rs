@@ -0,0 +1,156 @@
+use crate::Picture;
+use std::io::{self, Write};
+use std::time::Duration;
+
+const CLEAR_SCREEN: &str = "\x1b[2J";
+const CURSOR_HOME: &str = "\x1b[H";
+const HIDE_CURSOR: &str = "\x1b[?25l";
+const SHOW_CURSOR: &str = "\x1b[?25h";
+
+/// Which steps of a simulation are drawn
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StepFilter {
+    pub kind: Option<String>, // Only steps of this kind, such as "cycle"
+    pub every: usize,         // Every nth of the remaining steps
+}
+
+impl Default for StepFilter {
+    fn default() -> Self {
+        StepFilter {
+            kind: None,
+            every: 1,
+        }
+    }
+}
+
+/// Terminal animation that redraws the grid of a simulation after its steps
+pub struct Animation<'a> {
+    out: &'a mut dyn Write,
+    delay: Duration, // Pause after every drawn frame
+    filter: StepFilter,
+    steps: Vec<(String, usize)>, // Steps taken so far by kind
+    matched: usize,              // Steps that the kind filter let through
+    frames: usize,
+    error: Option<io::Error>, // Drawing stops at the first failed write
+}
+
+impl<'a> Animation<'a> {
+    pub fn new(out: &'a mut dyn Write, delay: Duration, filter: StepFilter) -> Self {
+        Animation {
+            out,
+            delay,
+            filter,
+            steps: Vec::new(),
+            matched: 0,
+            frames: 0,
+            error: None,
+        }
+    }
+
+    /// Counts a step of the given kind and draws the picture if the filter lets the step
+    /// through. The picture is only built for the steps that are drawn.
+    pub fn step(&mut self, kind: &str, picture: impl FnOnce() -> Picture) {
+        let number = match self.steps.iter_mut().find(|(name, _)| name == kind) {
+            Some((_, count)) => {
+                *count += 1;
+                *count
+            }
+            None => {
+                self.steps.push((kind.to_string(), 1));
+                1
+            }
+        };
+        if self.error.is_some() || self.filter.kind.as_deref().is_some_and(|k| k != kind) {
+            return;
+        }
+        self.matched += 1;
+        if !(self.matched - 1).is_multiple_of(self.filter.every) {
+            return;
+        }
+
+        let caption = format!("{} {}", kind, number);
+        if let Err(err) = self.draw(&picture(), &caption) {
+            self.error = Some(err);
+            return;
+        }
+        self.frames += 1;
+        std::thread::sleep(self.delay);
+    }
+
+    fn draw(&mut self, picture: &Picture, caption: &str) -> io::Result<()> {
+        if self.frames == 0 {
+            write!(self.out, "{}{}", CLEAR_SCREEN, HIDE_CURSOR)?;
+        }
+        // Clearing the caption line keeps a shorter caption from showing the end of a longer one
+        writeln!(
+            self.out,
+            "{}{}{}\x1b[K",
+            CURSOR_HOME,
+            picture.to_ansi(),
+            caption
+        )?;
+        self.out.flush()
+    }
+
+    /// Restores the cursor and returns the number of frames drawn
+    pub fn finish(self) -> io::Result<usize> {
+        if let Some(err) = self.error {
+            return Err(err);
+        }
+        if self.frames > 0 {
+            write!(self.out, "{}", SHOW_CURSOR)?;
+            self.out.flush()?;
+        }
+        Ok(self.frames)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Grid, Rgb};
+
+    fn frames(filter: StepFilter) -> (usize, String) {
+        let grid = Grid::filled(1, 1, ());
+        let mut out: Vec<u8> = Vec::new();
+        let mut animation = Animation::new(&mut out, Duration::ZERO, filter);
+        for _ in 0..3 {
+            for _ in 0..4 {
+                animation.step("tilt", || Picture::new(&grid, |_| Rgb(0, 0, 0)));
+            }
+            animation.step("cycle", || Picture::new(&grid, |_| Rgb(0, 0, 0)));
+        }
+        let frames = animation.finish().unwrap();
+        (frames, String::from_utf8(out).unwrap())
+    }
+
+    #[test]
+    fn test_step_filter() {
+        assert_eq!(15, frames(StepFilter::default()).0);
+        let cycles = StepFilter {
+            kind: Some("cycle".to_string()),
+            every: 1,
+        };
+        let (count, out) = frames(cycles);
+        assert_eq!(3, count);
+        assert!(out.contains("cycle 3"));
+        assert!(!out.contains("tilt"));
+        let every_fourth = StepFilter {
+            kind: Some("tilt".to_string()),
+            every: 4,
+        };
+        let (count, out) = frames(every_fourth);
+        assert_eq!(3, count);
+        assert!(out.contains("tilt 1\x1b[K\n") && out.contains("tilt 9\x1b[K\n"));
+        assert!(out.starts_with(CLEAR_SCREEN) && out.ends_with(SHOW_CURSOR));
+    }
+
+    #[test]
+    fn test_nothing_drawn() {
+        let unknown = StepFilter {
+            kind: Some("spin".to_string()),
+            every: 1,
+        };
+        assert_eq!((0, String::new()), frames(unknown));
+    }
+}
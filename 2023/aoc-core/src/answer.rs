@@ -0,0 +1,61 @@
+use std::fmt;
+
+/// Answer to one part of a puzzle
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Signed(i128),
+    Unsigned(u128),
+    Text(String),
+    Unsolved,
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Signed(number) => write!(f, "{}", number),
+            Answer::Unsigned(number) => write!(f, "{}", number),
+            Answer::Text(text) => write!(f, "{}", text),
+            Answer::Unsolved => write!(f, "unsolved"),
+        }
+    }
+}
+
+macro_rules! impl_from_integer {
+    ($variant:ident, $wide:ty, $($integer:ty),*) => {
+        $(
+            impl From<$integer> for Answer {
+                fn from(number: $integer) -> Self {
+                    Answer::$variant(number as $wide)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_integer!(Signed, i128, i8, i16, i32, i64, i128, isize);
+impl_from_integer!(Unsigned, u128, u8, u16, u32, u64, u128, usize);
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::Text(text.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!("-42", Answer::from(-42i64).to_string());
+        assert_eq!(u128::MAX.to_string(), Answer::from(u128::MAX).to_string());
+        assert_eq!("abc", Answer::from("abc").to_string());
+        assert_eq!("unsolved", Answer::Unsolved.to_string());
+    }
+}
@@ -0,0 +1,96 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// States of a simulation up to the point where they start repeating
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cycle<S> {
+    pub start: usize,  // Step at which the repeating states begin
+    pub length: usize, // Steps until a state comes back
+    states: Vec<S>,    // Every distinct state, in the order they were reached
+}
+
+impl<S> Cycle<S> {
+    /// State after the given number of steps from the initial one
+    pub fn state_at(&self, step: usize) -> &S {
+        &self.states[self.index_of(step)]
+    }
+
+    /// Step before the repeat that has the same state as the given step
+    pub fn index_of(&self, step: usize) -> usize {
+        match step < self.start {
+            true => step,
+            false => self.start + (step - self.start) % self.length,
+        }
+    }
+
+    /// States of the steps before the first repeat, starting from the initial state
+    pub fn states(&self) -> &[S] {
+        &self.states
+    }
+
+    pub fn into_state_at(mut self, step: usize) -> S {
+        self.states.swap_remove(self.index_of(step))
+    }
+}
+
+/// Steps from the initial state until a state repeats. Never returns if the states never
+/// repeat.
+pub fn find_cycle<S: Clone + Eq + Hash>(initial: S, next: impl FnMut(&S) -> S) -> Cycle<S> {
+    find_cycle_by_key(initial, next, S::clone)
+}
+
+/// Like `find_cycle` for states that are not hashable themselves or costly to hash. States
+/// with the same key count as the same state, so the key has to tell every state apart,
+/// like a fingerprint of the parts that change.
+pub fn find_cycle_by_key<S, K: Eq + Hash>(
+    initial: S,
+    mut next: impl FnMut(&S) -> S,
+    key: impl Fn(&S) -> K,
+) -> Cycle<S> {
+    let mut seen: HashMap<K, usize> = HashMap::from([(key(&initial), 0)]);
+    let mut states: Vec<S> = vec![initial];
+    loop {
+        let state = next(states.last().expect("there is always an initial state"));
+        if let Some(&start) = seen.get(&key(&state)) {
+            return Cycle {
+                start,
+                length: states.len() - start,
+                states,
+            };
+        }
+        seen.insert(key(&state), states.len());
+        states.push(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_cycle() {
+        // 1, 2, 4, 8, 16, 12, 4, ... when doubling modulo 20
+        let cycle = find_cycle(1u32, |n| n * 2 % 20);
+        assert_eq!((2, 4), (cycle.start, cycle.length));
+        assert_eq!(&[1, 2, 4, 8, 16, 12], cycle.states());
+        let states: Vec<u32> = (0..12).map(|step| *cycle.state_at(step)).collect();
+        assert_eq!(vec![1, 2, 4, 8, 16, 12, 4, 8, 16, 12, 4, 8], states);
+        assert_eq!(12, *cycle.state_at(1_000_000_001));
+        assert_eq!(12, cycle.into_state_at(1_000_000_001));
+
+        let fixed = find_cycle('a', |&c| c);
+        assert_eq!((0, 1, 'a'), (fixed.start, fixed.length, *fixed.state_at(5)));
+    }
+
+    #[test]
+    fn test_find_cycle_by_key() {
+        // The step counter makes every state different, so only the position is compared
+        let cycle = find_cycle_by_key(
+            (0u32, 0u32),
+            |&(n, steps)| ((n + 3) % 7, steps + 1),
+            |s| s.0,
+        );
+        assert_eq!((0, 7), (cycle.start, cycle.length));
+        assert_eq!((5, 4), *cycle.state_at(11));
+    }
+}
@@ -0,0 +1,120 @@
+use std::fmt;
+
+/// Malformed puzzle input, located by the line and column of the offending text
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: u8,
+    pub line: usize,   // 1-based, 0 if the offending text is not part of the input
+    pub column: usize, // 1-based, counted in characters
+    pub length: usize, // Length of the offending text in characters
+    pub snippet: String,
+    pub message: String,
+}
+
+impl ParseError {
+    /// Creates an error pointing at `span`, which should be a slice of `input`
+    pub fn new(day: u8, input: &str, span: &str, message: impl Into<String>) -> Self {
+        let length = span.chars().count();
+        let message = message.into();
+        let Some(offset) = offset_in(input, span) else {
+            return ParseError {
+                day,
+                line: 0,
+                column: 0,
+                length,
+                snippet: span.to_string(),
+                message,
+            };
+        };
+
+        // Locate the whole line containing the offending text
+        let line_start = input[..offset].rfind('\n').map_or(0, |index| index + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |index| offset + index);
+        let snippet = input[line_start..line_end].trim_end_matches('\r');
+        ParseError {
+            day,
+            line: input[..offset].matches('\n').count() + 1,
+            column: input[line_start..offset].chars().count() + 1,
+            length,
+            snippet: snippet.to_string(),
+            message,
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.line == 0 {
+            return write!(
+                f,
+                "day {:02}: {}: '{}'",
+                self.day, self.message, self.snippet
+            );
+        }
+        writeln!(
+            f,
+            "day {:02}, line {}, column {}: {}",
+            self.day, self.line, self.column, self.message
+        )?;
+        writeln!(f, "    {}", self.snippet)?;
+        write!(
+            f,
+            "    {}{}",
+            " ".repeat(self.column - 1),
+            "^".repeat(self.length.max(1))
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Error of a shared parsing helper, located by the slice of the parsed text that caused it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpanError<'a> {
+    pub span: &'a str,
+    pub message: &'static str,
+}
+
+impl SpanError<'_> {
+    /// Locates the error within the whole puzzle input
+    pub fn into_parse_error(self, day: u8, input: &str) -> ParseError {
+        ParseError::new(day, input, self.span, self.message)
+    }
+}
+
+// Byte offset of `span` within `input` if it is a slice of it
+fn offset_in(input: &str, span: &str) -> Option<usize> {
+    let start = input.as_ptr() as usize;
+    let span_start = span.as_ptr() as usize;
+    let within = start <= span_start && span_start + span.len() <= start + input.len();
+    within.then(|| span_start - start)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_locate_span() {
+        let input = "Game 1: 3 blue\nGame 2: 6 purple, 1 red\n";
+        let span = &input[input.find("purple").unwrap()..][..6];
+        let error = ParseError::new(2, input, span, "unknown cube color");
+        assert_eq!((2, 11, 6), (error.line, error.column, error.length));
+        assert_eq!("Game 2: 6 purple, 1 red", error.snippet);
+        assert_eq!(
+            "day 02, line 2, column 11: unknown cube color
+    Game 2: 6 purple, 1 red
+              ^^^^^^",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_span_outside_input() {
+        let error = ParseError::new(5, "seeds: 1 2", "owned", "not a number");
+        assert_eq!(0, error.line);
+        assert_eq!("day 05: not a number: 'owned'", error.to_string());
+    }
+}
@@ -0,0 +1,190 @@
+//! Example inputs with their expected answers, stored as files in each day's `fixtures`
+//! directory:
+//!
+//! - `<name>.txt` is the example input
+//! - `<name>.part1` and `<name>.part2` hold the expected answers of the parts the example is
+//!   for
+//! - an added `.todo` extension, like `<name>.part1.todo`, marks a part that is not solved yet
+//!   and ignores its test
+//!
+//! The build script of a day calls `generate_tests`, and the day includes the generated tests
+//! with `aoc_core::fixture_tests!()`.
+
+use crate::{Part, Puzzle};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub const FIXTURES_DIR: &str = "fixtures";
+const GENERATED_FILE: &str = "fixture_tests.rs";
+
+/// Includes the tests generated from the day's fixtures
+#[macro_export]
+macro_rules! fixture_tests {
+    () => {
+        #[cfg(test)]
+        mod fixtures {
+            include!(concat!(env!("OUT_DIR"), "/fixture_tests.rs"));
+        }
+    };
+}
+
+#[derive(Debug, PartialEq)]
+struct Fixture {
+    name: String,
+    input: PathBuf,
+    part: Part,
+    expected: PathBuf,
+    solved: bool,
+}
+
+/// Generates one test per fixture and part, run from a day's build script
+pub fn generate_tests() {
+    let manifest_dir = PathBuf::from(env_var("CARGO_MANIFEST_DIR"));
+    let out_dir = PathBuf::from(env_var("OUT_DIR"));
+    let fixtures_dir = manifest_dir.join(FIXTURES_DIR);
+    println!("cargo:rerun-if-changed={}", fixtures_dir.display());
+
+    // Days are named `dayNN` and their solutions `DayNN`
+    let package = env_var("CARGO_PKG_NAME");
+    let solution = format!("{}{}", package[..1].to_uppercase(), &package[1..]);
+    let fixtures = find_fixtures(&fixtures_dir).unwrap_or_else(|err| panic!("{}", err));
+    fs::write(
+        out_dir.join(GENERATED_FILE),
+        render_tests(&solution, &fixtures),
+    )
+    .expect("generated tests should be writable");
+}
+
+/// Solves the part of the example and compares the answer to the expected one
+pub fn check(puzzle: &dyn Puzzle, input: &str, part: Part, expected: &str) {
+    match puzzle.solve(input, part) {
+        Ok(answer) => assert_eq!(expected.trim_end(), answer.to_string()),
+        Err(err) => panic!("{}", err),
+    }
+}
+
+fn env_var(name: &str) -> String {
+    std::env::var(name).unwrap_or_else(|_| panic!("{} should be set by cargo", name))
+}
+
+fn find_fixtures(dir: &Path) -> Result<Vec<Fixture>, String> {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(_) => return Ok(Vec::new()), // Days without examples have no fixtures
+    };
+    let mut fixtures = Vec::new();
+    for entry in entries {
+        let path = entry.map_err(|err| err.to_string())?.path();
+        let file_name = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .unwrap_or_default();
+        let (file_name, solved) = match file_name.strip_suffix(".todo") {
+            Some(file_name) => (file_name, false),
+            None => (file_name, true),
+        };
+        let Some((name, extension)) = file_name.rsplit_once('.') else {
+            continue;
+        };
+        let part = match extension {
+            "part1" => Part::One,
+            "part2" => Part::Two,
+            _ => continue,
+        };
+        let input = dir.join(format!("{}.txt", name));
+        if !input.is_file() {
+            return Err(format!(
+                "{} has no input {}",
+                path.display(),
+                input.display()
+            ));
+        }
+        fixtures.push(Fixture {
+            name: name.to_string(),
+            input,
+            part,
+            expected: path,
+            solved,
+        });
+    }
+    fixtures.sort_by_key(|fixture| (fixture.name.clone(), fixture.part.number()));
+    Ok(fixtures)
+}
+
+fn render_tests(solution: &str, fixtures: &[Fixture]) -> String {
+    let mut tests = String::new();
+    for fixture in fixtures {
+        let name: String = fixture
+            .name
+            .chars()
+            .map(|c| match c.is_ascii_alphanumeric() {
+                true => c.to_ascii_lowercase(),
+                false => '_',
+            })
+            .collect();
+        tests.push_str("#[test]\n");
+        if !fixture.solved {
+            tests.push_str("#[ignore = \"not solved yet\"]\n");
+        }
+        tests.push_str(&format!(
+            r#"fn {}_part{}() {{
+    let input = include_str!({:?});
+    let expected = include_str!({:?});
+    let part = aoc_core::Part::{:?};
+    aoc_core::fixtures::check(&crate::{}, input, part, expected);
+}}
+
+"#,
+            name,
+            fixture.part.number(),
+            fixture.input,
+            fixture.expected,
+            fixture.part,
+            solution
+        ));
+    }
+    tests
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::temp::TempDir;
+
+    #[test]
+    fn test_find_fixtures() {
+        let dir = TempDir::new("fixtures");
+        let files = [
+            "example.txt",
+            "example.part2",
+            "example.part1",
+            "larger.txt",
+            "larger.part1.todo",
+            "notes.md",
+        ];
+        for file in files {
+            fs::write(dir.join(file), "").unwrap();
+        }
+
+        let fixtures = find_fixtures(&dir).unwrap();
+        let found: Vec<(&str, Part, bool)> = fixtures
+            .iter()
+            .map(|fixture| (fixture.name.as_str(), fixture.part, fixture.solved))
+            .collect();
+        let expected = [
+            ("example", Part::One, true),
+            ("example", Part::Two, true),
+            ("larger", Part::One, false),
+        ];
+        assert_eq!(expected.to_vec(), found);
+
+        let tests = render_tests("Day05", &fixtures);
+        assert_eq!(3, tests.matches("#[test]").count());
+        assert!(tests.contains("#[ignore = \"not solved yet\"]\nfn larger_part1()"));
+        assert!(tests.contains("check(&crate::Day05, input, part, expected)"));
+
+        // Expected answers need an input to check against
+        fs::remove_file(dir.join("larger.txt")).unwrap();
+        assert!(find_fixtures(&dir).is_err());
+    }
+}
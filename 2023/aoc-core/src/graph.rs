@@ -0,0 +1,359 @@
+//! Graph searches over nodes of any type. Graphs are given as a function that lists the
+//! neighbours of a node, so the same searches work on grid positions, labelled node maps or
+//! states of a puzzle.
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+/// Fewest steps from the start to every node that can be reached from it
+pub fn bfs<N, I>(start: N, mut neighbors: impl FnMut(&N) -> I) -> HashMap<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut distances: HashMap<N, usize> = HashMap::from([(start.clone(), 0)]);
+    let mut queue: VecDeque<N> = VecDeque::from([start]);
+    while let Some(node) = queue.pop_front() {
+        let distance = distances[&node] + 1;
+        for next in neighbors(&node) {
+            if !distances.contains_key(&next) {
+                distances.insert(next.clone(), distance);
+                queue.push_back(next);
+            }
+        }
+    }
+    distances
+}
+
+/// Path with the fewest steps from the start to the first goal found, including both ends
+pub fn bfs_to<N, I>(
+    start: N,
+    mut neighbors: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Vec<N>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    // The start is its own parent, which ends the path when walking it back
+    let mut parents: HashMap<N, N> = HashMap::from([(start.clone(), start.clone())]);
+    let mut queue: VecDeque<N> = VecDeque::from([start]);
+    while let Some(node) = queue.pop_front() {
+        if is_goal(&node) {
+            return Some(path_to(&parents, node));
+        }
+        for next in neighbors(&node) {
+            if !parents.contains_key(&next) {
+                parents.insert(next.clone(), node.clone());
+                queue.push_back(next);
+            }
+        }
+    }
+    None
+}
+
+fn path_to<N: Clone + Eq + Hash>(parents: &HashMap<N, N>, end: N) -> Vec<N> {
+    let mut path: Vec<N> = vec![end];
+    while let Some(parent) = parents.get(path.last().expect("path is never empty")) {
+        if path.last() == Some(parent) {
+            break;
+        }
+        path.push(parent.clone());
+    }
+    path.reverse();
+    path
+}
+
+/// Cheapest path from the start to the first goal found, and its cost. The neighbours come
+/// with the cost of the step to them.
+pub fn dijkstra<N, I>(
+    start: N,
+    neighbors: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<(Vec<N>, u64)>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = (N, u64)>,
+{
+    astar(start, neighbors, |_| 0, is_goal)
+}
+
+/// Like `dijkstra`, but searches towards the goal first. The heuristic estimates the cost from
+/// a node to the goal and must never estimate more than the cheapest actual cost, or the path
+/// found may not be the cheapest.
+pub fn astar<N, I>(
+    start: N,
+    mut neighbors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> u64,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<(Vec<N>, u64)>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = (N, u64)>,
+{
+    // Nodes are numbered in the order they are found, so that the queue needs no ordering of
+    // the nodes themselves
+    let mut indices: HashMap<N, usize> = HashMap::from([(start.clone(), 0)]);
+    let mut nodes: Vec<(N, usize, u64)> = vec![(start.clone(), 0, 0)]; // Node, parent and cost
+    let mut queue: BinaryHeap<Reverse<(u64, u64, usize)>> = BinaryHeap::new();
+    queue.push(Reverse((heuristic(&start), 0, 0)));
+    while let Some(Reverse((_, cost, index))) = queue.pop() {
+        // A cheaper way to the node was found after this entry was queued
+        if cost > nodes[index].2 {
+            continue;
+        }
+        let node = nodes[index].0.clone();
+        if is_goal(&node) {
+            let mut path: Vec<N> = vec![node];
+            let mut current = index;
+            while current != 0 {
+                current = nodes[current].1;
+                path.push(nodes[current].0.clone());
+            }
+            path.reverse();
+            return Some((path, cost));
+        }
+        for (next, step) in neighbors(&node) {
+            let next_cost = cost + step;
+            let next_index = match indices.get(&next) {
+                Some(&known) if nodes[known].2 <= next_cost => continue,
+                Some(&known) => {
+                    nodes[known] = (next.clone(), index, next_cost);
+                    known
+                }
+                None => {
+                    indices.insert(next.clone(), nodes.len());
+                    nodes.push((next.clone(), index, next_cost));
+                    nodes.len() - 1
+                }
+            };
+            queue.push(Reverse((
+                next_cost + heuristic(&next),
+                next_cost,
+                next_index,
+            )));
+        }
+    }
+    None
+}
+
+/// Every node that can be reached from the start, in the order they were found
+pub fn flood_fill<N, I>(start: N, mut neighbors: impl FnMut(&N) -> I) -> Vec<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut seen: HashSet<N> = HashSet::from([start.clone()]);
+    let mut filled: Vec<N> = vec![start];
+    let mut index = 0;
+    while let Some(node) = filled.get(index) {
+        let new: Vec<N> = neighbors(node)
+            .into_iter()
+            .filter(|next| seen.insert(next.clone()))
+            .collect();
+        filled.extend(new);
+        index += 1;
+    }
+    filled
+}
+
+/// Groups of nodes that can be reached from each other, in the order of the nodes that they
+/// were first found from. The neighbours have to go both ways.
+pub fn connected_components<N, I>(
+    nodes: impl IntoIterator<Item = N>,
+    mut neighbors: impl FnMut(&N) -> I,
+) -> Vec<Vec<N>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut seen: HashSet<N> = HashSet::new();
+    let mut components: Vec<Vec<N>> = Vec::new();
+    for node in nodes {
+        if seen.contains(&node) {
+            continue;
+        }
+        let component = flood_fill(node, &mut neighbors);
+        seen.extend(component.iter().cloned());
+        components.push(component);
+    }
+    components
+}
+
+/// Shortest cycle through the start, beginning with the start and without repeating it at
+/// the end. The neighbours have to go both ways, and a cycle never goes back along the edge
+/// it just came through.
+pub fn cycle_through<N, I>(start: N, mut neighbors: impl FnMut(&N) -> I) -> Option<Vec<N>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    // Each node remembers its parent, its distance from the start, and through which
+    // neighbour of the start it was found. An edge between nodes found through different
+    // neighbours closes a cycle.
+    let mut found: HashMap<N, (N, usize, usize)> = HashMap::new();
+    let mut queue: VecDeque<N> = VecDeque::new();
+    for (branch, first) in neighbors(&start).into_iter().enumerate() {
+        if first != start && !found.contains_key(&first) {
+            found.insert(first.clone(), (start.clone(), 1, branch));
+            queue.push_back(first);
+        }
+    }
+
+    let mut shortest: Option<(usize, N, N)> = None;
+    while let Some(node) = queue.pop_front() {
+        let (_, distance, branch) = found[&node].clone();
+        if shortest
+            .as_ref()
+            .is_some_and(|(length, _, _)| *length <= 2 * distance)
+        {
+            // Cycles closed from here on cannot be shorter
+            break;
+        }
+        for next in neighbors(&node) {
+            if next == start {
+                continue;
+            }
+            match found.get(&next) {
+                None => {
+                    found.insert(next.clone(), (node.clone(), distance + 1, branch));
+                    queue.push_back(next);
+                }
+                Some((_, next_distance, next_branch)) if *next_branch != branch => {
+                    let length = distance + next_distance + 1;
+                    if shortest.as_ref().is_none_or(|(best, _, _)| length < *best) {
+                        shortest = Some((length, node.clone(), next));
+                    }
+                }
+                Some(_) => {}
+            }
+        }
+    }
+
+    let (_, last_on_way_out, first_on_way_back) = shortest?;
+    let back_to_start = |mut node: N| {
+        let mut nodes: Vec<N> = Vec::new();
+        while node != start {
+            nodes.push(node.clone());
+            node = found[&node].0.clone();
+        }
+        nodes
+    };
+    let mut cycle: Vec<N> = vec![start.clone()];
+    cycle.extend(back_to_start(last_on_way_out).into_iter().rev());
+    cycle.extend(back_to_start(first_on_way_back));
+    Some(cycle)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::{Grid, Position};
+
+    // Open tiles of a maze, with walls '#'
+    fn maze(text: &str) -> Grid<bool> {
+        Grid::from_chars(text, |c| Ok(c != '#')).unwrap()
+    }
+
+    fn open_neighbors(grid: &Grid<bool>) -> impl FnMut(&Position) -> Vec<Position> + '_ {
+        |&position| grid.neighbors4(position).filter(|&p| grid[p]).collect()
+    }
+
+    const MAZE: &str = "\
+..#.#..
+.##.##.
+...#...
+.#...#.";
+
+    #[test]
+    fn test_bfs() {
+        let grid = maze(MAZE);
+        let distances = bfs((0, 0), open_neighbors(&grid));
+        assert_eq!(Some(&0), distances.get(&(0, 0)));
+        assert_eq!(Some(&7), distances.get(&(3, 4)));
+        assert_eq!(Some(&13), distances.get(&(0, 5)));
+        assert_eq!(None, distances.get(&(0, 3)));
+
+        let path = bfs_to((0, 0), open_neighbors(&grid), |&p| p == (2, 6)).unwrap();
+        assert_eq!(11, path.len());
+        assert_eq!((Some(&(0, 0)), Some(&(2, 6))), (path.first(), path.last()));
+        assert!(path
+            .windows(2)
+            .all(|pair| grid.neighbors4(pair[0]).any(|p| p == pair[1])));
+        assert_eq!(
+            None,
+            bfs_to((0, 0), open_neighbors(&grid), |&p| p == (1, 3))
+        );
+    }
+
+    #[test]
+    fn test_dijkstra_and_astar() {
+        // Labelled nodes with weighted edges
+        let edges: HashMap<&str, Vec<(&str, u64)>> = HashMap::from([
+            ("a", vec![("b", 7), ("c", 9), ("f", 14)]),
+            ("b", vec![("a", 7), ("c", 10), ("d", 15)]),
+            ("c", vec![("a", 9), ("b", 10), ("d", 11), ("f", 2)]),
+            ("d", vec![("b", 15), ("c", 11), ("e", 6)]),
+            ("e", vec![("d", 6), ("f", 9)]),
+            ("f", vec![("a", 14), ("c", 2), ("e", 9)]),
+        ]);
+        let neighbors = |node: &&str| edges[node].clone();
+        let expected = Some((vec!["a", "c", "f", "e"], 20));
+        assert_eq!(expected, dijkstra("a", neighbors, |&node| node == "e"));
+        assert_eq!(
+            Some((vec!["a"], 0)),
+            dijkstra("a", neighbors, |&node| node == "a")
+        );
+        assert_eq!(None, dijkstra("a", neighbors, |&node| node == "g"));
+
+        // The Manhattan distance never overestimates on a grid with steps of cost 1
+        let grid = maze(MAZE);
+        let steps = |&position: &Position| -> Vec<(Position, u64)> {
+            open_neighbors(&grid)(&position)
+                .into_iter()
+                .map(|next| (next, 1))
+                .collect()
+        };
+        let manhattan = |&(row, col): &Position| (row.abs_diff(3) + col.abs_diff(6)) as u64;
+        let (path, cost) = astar((0, 0), steps, manhattan, |&p| p == (3, 6)).unwrap();
+        assert_eq!((12, 11), (path.len(), cost));
+    }
+
+    #[test]
+    fn test_flood_fill_and_components() {
+        let grid = maze(MAZE);
+        let filled = flood_fill((0, 0), open_neighbors(&grid));
+        assert_eq!((Some(&(0, 0)), 17), (filled.first(), filled.len()));
+        assert_eq!(
+            vec![(0, 3), (1, 3)],
+            flood_fill((0, 3), open_neighbors(&grid))
+        );
+
+        let open: Vec<Position> = grid.positions(|&open| open).collect();
+        let components = connected_components(open, open_neighbors(&grid));
+        let sizes: Vec<usize> = components.iter().map(Vec::len).collect();
+        assert_eq!(vec![17, 2], sizes);
+        assert_eq!(vec![(0, 3), (1, 3)], components[1]);
+    }
+
+    #[test]
+    fn test_cycle_through() {
+        // A square a-b-c-d with a tail d-e and a triangle a-f-g
+        let edges: HashMap<char, &str> = HashMap::from([
+            ('a', "bdfg"),
+            ('b', "ac"),
+            ('c', "bd"),
+            ('d', "cae"),
+            ('e', "d"),
+            ('f', "ag"),
+            ('g', "fa"),
+        ]);
+        let neighbors = |node: &char| edges[node].chars().collect::<Vec<char>>();
+        assert_eq!(Some(vec!['a', 'f', 'g']), cycle_through('a', neighbors));
+        let cycle = cycle_through('c', neighbors).unwrap();
+        assert_eq!(4, cycle.len());
+        assert!(cycle[0] == 'c' && cycle.contains(&'a') && !cycle.contains(&'e'));
+        assert_eq!(None, cycle_through('e', neighbors));
+    }
+}
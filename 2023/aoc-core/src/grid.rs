@@ -0,0 +1,313 @@
+use crate::point::Vector;
+use crate::SpanError;
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+/// Position in a grid as (row, col)
+pub type Position = (usize, usize);
+
+const OFFSETS4: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+const OFFSETS8: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+/// Two-dimensional grid stored row by row in one contiguous vector
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    height: usize,
+    width: usize,
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid from cells listed row by row
+    pub fn new(height: usize, width: usize, cells: Vec<T>) -> Self {
+        assert_eq!(
+            height * width,
+            cells.len(),
+            "grid cells should fill every row"
+        );
+        Grid {
+            cells,
+            height,
+            width,
+        }
+    }
+
+    /// Creates a grid by calling `cell` for every position
+    pub fn from_fn(height: usize, width: usize, mut cell: impl FnMut(Position) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|row| (0..width).map(move |col| (row, col)))
+            .map(&mut cell)
+            .collect();
+        Grid::new(height, width, cells)
+    }
+
+    /// Creates a grid from lines of text by mapping each character to a cell
+    ///
+    /// The mapping returns the error message for characters that are not valid cells.
+    pub fn from_chars(
+        text: &str,
+        mut cell: impl FnMut(char) -> Result<T, &'static str>,
+    ) -> Result<Self, SpanError<'_>> {
+        let mut cells = Vec::with_capacity(text.len());
+        let mut height = 0;
+        let mut width = None;
+        for line in text.lines() {
+            for (index, c) in line.char_indices() {
+                cells.push(cell(c).map_err(|message| SpanError {
+                    span: &line[index..index + c.len_utf8()],
+                    message,
+                })?);
+            }
+            let line_width = cells.len() - height * width.unwrap_or(0);
+            if *width.get_or_insert(line_width) != line_width {
+                let message = "every row of the grid should have the same length";
+                return Err(SpanError {
+                    span: line,
+                    message,
+                });
+            }
+            height += 1;
+        }
+        Ok(Grid::new(height, width.unwrap_or(0), cells))
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn get(&self, (row, col): Position) -> Option<&T> {
+        (row < self.height && col < self.width).then(|| &self.cells[row * self.width + col])
+    }
+
+    pub fn get_mut(&mut self, (row, col): Position) -> Option<&mut T> {
+        (row < self.height && col < self.width).then(|| &mut self.cells[row * self.width + col])
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn row_mut(&mut self, row: usize) -> &mut [T] {
+        &mut self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // Zero-width grids have no cells to chunk, but still have rows
+        (0..self.height).map(|row| self.row(row))
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        assert!(col < self.width, "column {} is out of bounds", col);
+        self.cells.iter().skip(col).step_by(self.width)
+    }
+
+    /// Iterates over every cell with its position, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(index, cell)| ((index / self.width, index % self.width), cell))
+    }
+
+    /// Finds the first position, row by row, whose cell matches the predicate
+    pub fn position(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Position> {
+        self.positions(|cell| predicate(cell)).next()
+    }
+
+    /// Iterates over every position whose cell matches the predicate
+    pub fn positions<'a>(
+        &'a self,
+        mut predicate: impl FnMut(&T) -> bool + 'a,
+    ) -> impl Iterator<Item = Position> + 'a {
+        self.iter()
+            .filter(move |(_, cell)| predicate(cell))
+            .map(|(position, _)| position)
+    }
+
+    /// Iterates over the up, left, right and down neighbours inside the grid
+    pub fn neighbors4(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        OFFSETS4
+            .iter()
+            .filter_map(move |&offset| self.offset(position, offset))
+    }
+
+    /// Iterates over the orthogonal and diagonal neighbours inside the grid
+    pub fn neighbors8(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        OFFSETS8
+            .iter()
+            .filter_map(move |&offset| self.offset(position, offset))
+    }
+
+    /// Position one step away in the direction, or `None` when the step leaves the grid
+    pub fn step(&self, position: Position, direction: impl Into<Vector>) -> Option<Position> {
+        let offset: Vector = direction.into();
+        self.offset(position, (offset.y as isize, offset.x as isize))
+    }
+
+    /// Iterates from the position in the direction up to the edge of the grid
+    pub fn walk(
+        &self,
+        position: Position,
+        direction: impl Into<Vector>,
+    ) -> impl Iterator<Item = Position> + '_ {
+        let offset: Vector = direction.into();
+        let start = self.get(position).map(|_| position);
+        std::iter::successors(start, move |&position| self.step(position, offset))
+    }
+
+    fn offset(&self, (row, col): Position, (rows, cols): (isize, isize)) -> Option<Position> {
+        let position = (row.checked_add_signed(rows)?, col.checked_add_signed(cols)?);
+        self.get(position).map(|_| position)
+    }
+
+    pub fn map<U>(&self, mut cell: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::new(
+            self.height,
+            self.width,
+            self.cells.iter().map(&mut cell).collect(),
+        )
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Creates a grid with every cell set to `value`
+    pub fn filled(height: usize, width: usize, value: T) -> Self {
+        Grid::new(height, width, vec![value; height * width])
+    }
+
+    /// Mirrors the grid over its main diagonal, so rows become columns
+    pub fn transpose(&self) -> Self {
+        Grid::from_fn(self.width, self.height, |(row, col)| {
+            self[(col, row)].clone()
+        })
+    }
+
+    pub fn rotate_clockwise(&self) -> Self {
+        Grid::from_fn(self.width, self.height, |(row, col)| {
+            self[(self.height - 1 - col, row)].clone()
+        })
+    }
+
+    pub fn rotate_counterclockwise(&self) -> Self {
+        Grid::from_fn(self.width, self.height, |(row, col)| {
+            self[(col, self.width - 1 - row)].clone()
+        })
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): Position) -> &T {
+        assert!(
+            row < self.height && col < self.width,
+            "({}, {}) is out of bounds",
+            row,
+            col
+        );
+        &self.cells[row * self.width + col]
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, (row, col): Position) -> &mut T {
+        assert!(
+            row < self.height && col < self.width,
+            "({}, {}) is out of bounds",
+            row,
+            col
+        );
+        &mut self.cells[row * self.width + col]
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (index, row) in self.rows().enumerate() {
+            if index > 0 {
+                writeln!(f)?;
+            }
+            row.iter().try_for_each(|cell| write!(f, "{}", cell))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::point::{Direction4, Direction8};
+
+    fn grid(text: &str) -> Grid<char> {
+        Grid::from_chars(text, Ok).unwrap()
+    }
+
+    #[test]
+    fn test_from_chars() {
+        let grid = grid("abc\ndef");
+        assert_eq!((2, 3), (grid.height(), grid.width()));
+        assert_eq!('f', grid[(1, 2)]);
+        assert_eq!(None, grid.get((2, 0)));
+        assert_eq!(&['d', 'e', 'f'], grid.row(1));
+        assert_eq!("cf", grid.column(2).collect::<String>());
+        assert_eq!(Some((1, 1)), grid.position(|&c| c == 'e'));
+
+        let error = Grid::from_chars("ab\nc", Ok).unwrap_err();
+        assert_eq!("c", error.span);
+        let digits = |c: char| c.to_digit(10).ok_or("expected a digit");
+        let error = Grid::from_chars("12\n3x", digits).unwrap_err();
+        assert_eq!(("x", "expected a digit"), (error.span, error.message));
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = grid("abc\ndef\nghi");
+        let around = |positions: Vec<Position>| -> String {
+            positions
+                .into_iter()
+                .map(|position| grid[position])
+                .collect()
+        };
+        assert_eq!("bd", around(grid.neighbors4((0, 0)).collect()));
+        assert_eq!("bdfh", around(grid.neighbors4((1, 1)).collect()));
+        assert_eq!("abcdfghi", around(grid.neighbors8((1, 1)).collect()));
+        assert_eq!("efh", around(grid.neighbors8((2, 2)).collect()));
+    }
+
+    #[test]
+    fn test_step_and_walk() {
+        let grid = grid("abc\ndef\nghi");
+        assert_eq!(Some((0, 1)), grid.step((1, 1), Direction4::North));
+        assert_eq!(Some((2, 0)), grid.step((1, 1), Direction8::SouthWest));
+        assert_eq!(None, grid.step((0, 2), Direction4::East));
+        assert_eq!(None, grid.step((2, 1), Direction4::South));
+        let walked: String = grid
+            .walk((2, 2), Direction8::NorthWest)
+            .map(|position| grid[position])
+            .collect();
+        assert_eq!("iea", walked);
+        assert_eq!(0, grid.walk((3, 0), Direction4::North).count());
+    }
+
+    #[test]
+    fn test_transpose_and_rotate() {
+        let grid = grid("abc\ndef");
+        assert_eq!("ad\nbe\ncf", grid.transpose().to_string());
+        assert_eq!("da\neb\nfc", grid.rotate_clockwise().to_string());
+        assert_eq!("cf\nbe\nad", grid.rotate_counterclockwise().to_string());
+        assert_eq!(grid, grid.rotate_clockwise().rotate_counterclockwise());
+    }
+}
@@ -0,0 +1,388 @@
+use std::cmp::Ordering;
+
+/// Half-open range of integers from `start` up to but not including `end`, which is empty
+/// when `end` is not after `start`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Interval {
+    pub start: i64,
+    pub end: i64,
+}
+
+impl Interval {
+    pub fn new(start: i64, end: i64) -> Self {
+        Interval { start, end }
+    }
+
+    /// Interval of `length` numbers from `start`
+    pub fn with_length(start: i64, length: i64) -> Self {
+        Interval::new(start, start + length)
+    }
+
+    pub fn len(&self) -> u64 {
+        self.end.saturating_sub(self.start).max(0) as u64
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.end <= self.start
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        self.start <= value && value < self.end
+    }
+
+    /// Numbers in both intervals, or `None` when they do not overlap
+    pub fn intersection(&self, other: &Interval) -> Option<Interval> {
+        let overlap = Interval::new(self.start.max(other.start), self.end.min(other.end));
+        (!overlap.is_empty()).then_some(overlap)
+    }
+
+    /// Every number moved by the offset
+    pub fn shift(&self, offset: i64) -> Interval {
+        Interval::new(self.start + offset, self.end + offset)
+    }
+}
+
+/// Set of integers kept as sorted intervals that neither overlap nor touch
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct RangeSet {
+    intervals: Vec<Interval>,
+}
+
+impl RangeSet {
+    pub fn new() -> Self {
+        RangeSet::default()
+    }
+
+    pub fn intervals(&self) -> &[Interval] {
+        &self.intervals
+    }
+
+    /// Number of integers in the set
+    pub fn len(&self) -> u64 {
+        self.intervals.iter().map(Interval::len).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    pub fn min(&self) -> Option<i64> {
+        self.intervals.first().map(|interval| interval.start)
+    }
+
+    pub fn max(&self) -> Option<i64> {
+        self.intervals.last().map(|interval| interval.end - 1)
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        self.intervals
+            .binary_search_by(|interval| match interval {
+                _ if interval.end <= value => Ordering::Less,
+                _ if value < interval.start => Ordering::Greater,
+                _ => Ordering::Equal,
+            })
+            .is_ok()
+    }
+
+    pub fn insert(&mut self, interval: Interval) {
+        *self = self.union(&RangeSet::from(interval));
+    }
+
+    pub fn union(&self, other: &RangeSet) -> RangeSet {
+        self.intervals
+            .iter()
+            .chain(&other.intervals)
+            .copied()
+            .collect()
+    }
+
+    pub fn intersection(&self, other: &RangeSet) -> RangeSet {
+        // Both lists are sorted, so walk them together and step past whichever ends first
+        let (mut intervals, mut i, mut j) = (Vec::new(), 0, 0);
+        while let (Some(a), Some(b)) = (self.intervals.get(i), other.intervals.get(j)) {
+            intervals.extend(a.intersection(b));
+            match a.end <= b.end {
+                true => i += 1,
+                false => j += 1,
+            }
+        }
+        RangeSet { intervals }
+    }
+
+    /// Integers of this set that are not in the other
+    pub fn difference(&self, other: &RangeSet) -> RangeSet {
+        let mut intervals = Vec::new();
+        let mut others = other.intervals.iter().peekable();
+        for interval in &self.intervals {
+            let mut start = interval.start;
+            // Other intervals that end before this one starts cannot cut later ones either
+            while others.next_if(|cut| cut.end <= start).is_some() {}
+            for cut in others.clone() {
+                if cut.start >= interval.end {
+                    break;
+                }
+                if cut.start > start {
+                    intervals.push(Interval::new(start, cut.start));
+                }
+                start = start.max(cut.end);
+            }
+            if start < interval.end {
+                intervals.push(Interval::new(start, interval.end));
+            }
+        }
+        RangeSet { intervals }
+    }
+}
+
+impl From<Interval> for RangeSet {
+    fn from(interval: Interval) -> Self {
+        RangeSet::from_iter([interval])
+    }
+}
+
+impl FromIterator<Interval> for RangeSet {
+    fn from_iter<I: IntoIterator<Item = Interval>>(iter: I) -> Self {
+        let mut sorted: Vec<Interval> = iter
+            .into_iter()
+            .filter(|interval| !interval.is_empty())
+            .collect();
+        sorted.sort_unstable_by_key(|interval| interval.start);
+        let mut intervals: Vec<Interval> = Vec::with_capacity(sorted.len());
+        for interval in sorted {
+            match intervals.last_mut() {
+                Some(last) if interval.start <= last.end => last.end = last.end.max(interval.end),
+                _ => intervals.push(interval),
+            }
+        }
+        RangeSet { intervals }
+    }
+}
+
+/// Piece of a `PiecewiseMap` that moves the numbers of its source interval by an offset
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Piece {
+    pub source: Interval,
+    pub offset: i64,
+}
+
+impl Piece {
+    /// Interval that the source maps onto
+    pub fn image(&self) -> Interval {
+        self.source.shift(self.offset)
+    }
+}
+
+/// Mapping of integers that moves each of a number of disjoint intervals by its own offset
+/// and leaves every other number as it is
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct PiecewiseMap {
+    pieces: Vec<Piece>, // Sorted by source, without overlaps
+}
+
+impl PiecewiseMap {
+    /// Identity map
+    pub fn new() -> Self {
+        PiecewiseMap::default()
+    }
+
+    pub fn pieces(&self) -> &[Piece] {
+        &self.pieces
+    }
+
+    /// Moves the numbers of the source interval by the offset. Numbers that an earlier piece
+    /// already maps keep their mapping.
+    pub fn insert(&mut self, source: Interval, offset: i64) {
+        let mapped: RangeSet = self.pieces.iter().map(|piece| piece.source).collect();
+        let new = RangeSet::from(source).difference(&mapped);
+        // A zero offset is kept as a piece too, so that later pieces cannot map its numbers
+        let pieces = new
+            .intervals
+            .into_iter()
+            .map(|source| Piece { source, offset });
+        self.pieces.extend(pieces);
+        self.pieces.sort_unstable_by_key(|piece| piece.source.start);
+    }
+
+    pub fn apply(&self, value: i64) -> i64 {
+        self.piece_containing(value)
+            .map_or(value, |piece| value + piece.offset)
+    }
+
+    fn piece_containing(&self, value: i64) -> Option<&Piece> {
+        let index = self
+            .pieces
+            .partition_point(|piece| piece.source.end <= value);
+        self.pieces
+            .get(index)
+            .filter(|piece| piece.source.contains(value))
+    }
+
+    /// Numbers that the numbers of the interval map onto
+    pub fn apply_range(&self, interval: Interval) -> RangeSet {
+        self.apply_set(&RangeSet::from(interval))
+    }
+
+    pub fn apply_set(&self, set: &RangeSet) -> RangeSet {
+        let sources: RangeSet = self.pieces.iter().map(|piece| piece.source).collect();
+        let moved = self.pieces.iter().flat_map(|piece| {
+            set.intersection(&RangeSet::from(piece.source))
+                .intervals
+                .into_iter()
+                .map(|interval| interval.shift(piece.offset))
+        });
+        // Numbers outside of every piece map onto themselves
+        set.difference(&sources)
+            .intervals
+            .into_iter()
+            .chain(moved)
+            .collect()
+    }
+
+    /// Map that applies this map and then the next one
+    pub fn then(&self, next: &PiecewiseMap) -> PiecewiseMap {
+        let mut composed = PiecewiseMap::new();
+        for piece in &self.pieces {
+            // Split the image of the piece where the pieces of the next map begin and end
+            let image = RangeSet::from(piece.image());
+            for next_piece in &next.pieces {
+                for part in image
+                    .intersection(&RangeSet::from(next_piece.source))
+                    .intervals
+                {
+                    let source = part.shift(-piece.offset);
+                    composed.insert(source, piece.offset + next_piece.offset);
+                }
+            }
+            composed.insert(piece.source, piece.offset);
+        }
+        // Numbers that this map leaves as they are go through the next map alone
+        for next_piece in &next.pieces {
+            composed.insert(next_piece.source, next_piece.offset);
+        }
+        composed.pieces.retain(|piece| piece.offset != 0);
+        composed
+    }
+
+    /// Map that undoes this one, or `None` when two numbers map onto the same number or a
+    /// number is not mapped onto by any
+    pub fn inverse(&self) -> Option<PiecewiseMap> {
+        let sources: RangeSet = self.pieces.iter().map(|piece| piece.source).collect();
+        let images: RangeSet = self.pieces.iter().map(Piece::image).collect();
+        // Numbers outside the pieces map onto themselves, so the pieces have to cover their
+        // sources exactly once. The images add up to as many numbers as the sources, so they
+        // can only cover the sources if they do not overlap.
+        if images != sources {
+            return None;
+        }
+        let mut inverse = PiecewiseMap::new();
+        for piece in &self.pieces {
+            inverse.insert(piece.image(), -piece.offset);
+        }
+        Some(inverse)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(intervals: &[(i64, i64)]) -> RangeSet {
+        intervals
+            .iter()
+            .map(|&(start, end)| Interval::new(start, end))
+            .collect()
+    }
+
+    fn map(pieces: &[(i64, i64, i64)]) -> PiecewiseMap {
+        let mut map = PiecewiseMap::new();
+        for &(start, end, offset) in pieces {
+            map.insert(Interval::new(start, end), offset);
+        }
+        map
+    }
+
+    #[test]
+    fn test_interval() {
+        let interval = Interval::with_length(5, 3);
+        assert_eq!((Interval::new(5, 8), 3), (interval, interval.len()));
+        assert!(interval.contains(7) && !interval.contains(8));
+        assert_eq!(0, Interval::new(4, 2).len());
+        assert_eq!(
+            Some(Interval::new(6, 8)),
+            interval.intersection(&Interval::new(6, 20))
+        );
+        assert_eq!(None, interval.intersection(&Interval::new(8, 20)));
+    }
+
+    #[test]
+    fn test_range_set() {
+        let a = set(&[(10, 15), (0, 5), (4, 8), (8, 9), (20, 20)]);
+        assert_eq!(&[Interval::new(0, 9), Interval::new(10, 15)], a.intervals());
+        assert_eq!((14, Some(0), Some(14)), (a.len(), a.min(), a.max()));
+        assert!(a.contains(8) && !a.contains(9) && a.contains(10) && !a.contains(-1));
+
+        let b = set(&[(3, 12), (14, 30)]);
+        assert_eq!(set(&[(0, 30)]), a.union(&b));
+        assert_eq!(set(&[(3, 9), (10, 12), (14, 15)]), a.intersection(&b));
+        assert_eq!(set(&[(0, 3), (12, 14)]), a.difference(&b));
+        assert_eq!(set(&[(9, 10), (15, 30)]), b.difference(&a));
+        assert!(a.difference(&a).is_empty());
+
+        let mut c = RangeSet::new();
+        c.insert(Interval::new(5, 7));
+        c.insert(Interval::new(1, 2));
+        c.insert(Interval::new(2, 5));
+        assert_eq!(set(&[(1, 7)]), c);
+    }
+
+    #[test]
+    fn test_apply() {
+        // The seed-to-soil map of the day 5 example
+        let map = map(&[(98, 100, -48), (50, 98, 2)]);
+        let soils: Vec<i64> = [79, 14, 55, 13, 98, 100]
+            .map(|seed| map.apply(seed))
+            .to_vec();
+        assert_eq!(vec![81, 14, 57, 13, 50, 100], soils);
+        let range = map.apply_range(Interval::new(40, 100));
+        assert_eq!(set(&[(40, 100)]), range);
+        let range = map.apply_range(Interval::new(95, 105));
+        assert_eq!(set(&[(50, 52), (97, 105)]), range);
+    }
+
+    #[test]
+    fn test_insert_keeps_earlier_pieces() {
+        let map = map(&[(0, 10, 100), (5, 15, 200), (20, 30, 0), (25, 35, 5)]);
+        assert_eq!(
+            vec![100, 109, 210, 214, 24, 29, 35, 39, 35],
+            [0, 9, 10, 14, 24, 29, 30, 34, 35]
+                .map(|n| map.apply(n))
+                .to_vec()
+        );
+    }
+
+    #[test]
+    fn test_then() {
+        let first = map(&[(0, 10, 5), (10, 15, -10)]);
+        let second = map(&[(3, 8, 100), (12, 20, 1)]);
+        let composed = first.then(&second);
+        for n in -5..30 {
+            assert_eq!(second.apply(first.apply(n)), composed.apply(n), "{}", n);
+        }
+        let identity = PiecewiseMap::new();
+        assert_eq!(first, first.then(&identity));
+        assert_eq!(first, identity.then(&first));
+    }
+
+    #[test]
+    fn test_inverse() {
+        // Swaps 0..5 with 10..15
+        let swap = map(&[(0, 5, 10), (10, 15, -10)]);
+        let inverse = swap.inverse().expect("swapping is invertible");
+        for n in -5..20 {
+            assert_eq!(n, inverse.apply(swap.apply(n)));
+        }
+        assert_eq!(None, map(&[(0, 5, 10)]).inverse());
+        assert_eq!(None, map(&[(0, 5, 10), (10, 15, -5)]).inverse());
+        assert_eq!(Some(PiecewiseMap::new()), PiecewiseMap::new().inverse());
+    }
+}
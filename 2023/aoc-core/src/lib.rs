@@ -0,0 +1,29 @@
+pub mod animate;
+pub mod answer;
+pub mod cycle;
+pub mod error;
+pub mod fixtures;
+pub mod graph;
+pub mod grid;
+pub mod interval;
+pub mod math;
+pub mod memory;
+pub mod numbers;
+pub mod point;
+pub mod random;
+pub mod render;
+pub mod solution;
+pub mod temp;
+pub mod trace;
+
+pub use animate::{Animation, StepFilter};
+pub use answer::Answer;
+pub use cycle::Cycle;
+pub use error::{ParseError, SpanError};
+pub use grid::Grid;
+pub use interval::{Interval, PiecewiseMap, RangeSet};
+pub use memory::Allocations;
+pub use point::{Direction4, Direction8, Point, Vector};
+pub use random::Rng;
+pub use render::{ImageFormat, Overlay, Picture, Rgb};
+pub use solution::{Generated, Part, Puzzle, Solution, Timed};
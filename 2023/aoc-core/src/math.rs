@@ -0,0 +1,241 @@
+use std::ops::{Add, Div, Rem, Shl, Shr, Sub};
+
+/// Unsigned integer types that the math functions work on
+pub trait Unsigned:
+    Copy
+    + Ord
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+    + Shl<u32, Output = Self>
+    + Shr<u32, Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+    const BITS: u32;
+
+    fn checked_mul(self, other: Self) -> Option<Self>;
+    fn leading_zeros(self) -> u32;
+    /// `self * other % modulus` without overflowing
+    fn mul_mod(self, other: Self, modulus: Self) -> Self;
+}
+
+impl Unsigned for u64 {
+    const ZERO: Self = 0;
+    const ONE: Self = 1;
+    const BITS: u32 = u64::BITS;
+
+    fn checked_mul(self, other: Self) -> Option<Self> {
+        u64::checked_mul(self, other)
+    }
+
+    fn leading_zeros(self) -> u32 {
+        u64::leading_zeros(self)
+    }
+
+    fn mul_mod(self, other: Self, modulus: Self) -> Self {
+        (self as u128 * other as u128 % modulus as u128) as u64
+    }
+}
+
+impl Unsigned for u128 {
+    const ZERO: Self = 0;
+    const ONE: Self = 1;
+    const BITS: u32 = u128::BITS;
+
+    fn checked_mul(self, other: Self) -> Option<Self> {
+        u128::checked_mul(self, other)
+    }
+
+    fn leading_zeros(self) -> u32 {
+        u128::leading_zeros(self)
+    }
+
+    fn mul_mod(self, other: Self, modulus: Self) -> Self {
+        if let Some(product) = self.checked_mul(other) {
+            return product % modulus;
+        }
+        // There is no wider type to multiply in, so add up the doublings of `self` instead
+        let add_mod = |a: u128, b: u128| match a.checked_add(b) {
+            Some(sum) => sum % modulus,
+            None => a.wrapping_add(b).wrapping_sub(modulus),
+        };
+        let (mut base, mut other, mut product) = (self % modulus, other, 0);
+        while other > 0 {
+            if other & 1 == 1 {
+                product = add_mod(product, base);
+            }
+            base = add_mod(base, base);
+            other >>= 1;
+        }
+        product
+    }
+}
+
+/// Greatest common divisor, which is 0 only when both numbers are 0
+pub fn gcd<T: Unsigned>(mut a: T, mut b: T) -> T {
+    while b != T::ZERO {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Least common multiple, or `None` when it does not fit the type
+pub fn lcm<T: Unsigned>(a: T, b: T) -> Option<T> {
+    if a == T::ZERO || b == T::ZERO {
+        return Some(T::ZERO);
+    }
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+/// Least common multiple of all the numbers, which is 1 for no numbers
+pub fn lcm_all<T: Unsigned>(numbers: impl IntoIterator<Item = T>) -> Option<T> {
+    numbers
+        .into_iter()
+        .try_fold(T::ONE, |multiple, number| lcm(multiple, number))
+}
+
+/// Greatest common divisor `g` with the coefficients `x` and `y` for which `a * x + b * y = g`
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+    match old_r < 0 {
+        true => (-old_r, -old_x, -old_y),
+        false => (old_r, old_x, old_y),
+    }
+}
+
+/// Smallest number that leaves every residue for its modulus, together with the modulus
+/// after which the solutions repeat. The moduli need not be coprime. `None` when the
+/// congruences contradict each other or the combined modulus does not fit in 64 bits.
+///
+/// Panics if a modulus is zero.
+pub fn crt(congruences: &[(u64, u64)]) -> Option<(u64, u64)> {
+    congruences.iter().try_fold(
+        (0, 1),
+        |(residue, modulus), &(other_residue, other_modulus)| {
+            assert!(other_modulus > 0, "modulus should not be zero");
+            let other_residue = other_residue % other_modulus;
+            let divisor = gcd(modulus, other_modulus);
+            let difference = other_residue as i128 - residue as i128;
+            if difference % divisor as i128 != 0 {
+                return None;
+            }
+            // Solve residue + modulus * k = other_residue for k modulo other_modulus / divisor
+            let step = other_modulus / divisor;
+            let combined = lcm(modulus, other_modulus)?;
+            let (_, inverse, _) = extended_gcd((modulus / divisor) as i128, step as i128);
+            let inverse = inverse.rem_euclid(step as i128) as u64;
+            let quotient = (difference / divisor as i128).rem_euclid(step as i128) as u64;
+            let k = quotient.mul_mod(inverse, step);
+            // k < step, so modulus * k < combined
+            Some((residue + modulus * k, combined))
+        },
+    )
+}
+
+/// Integer square root rounded down, without going through floating point
+pub fn isqrt<T: Unsigned>(n: T) -> T {
+    if n == T::ZERO {
+        return T::ZERO;
+    }
+    // Newton's method converges from above, so start from a power of two above the root
+    let bits = T::BITS - n.leading_zeros();
+    let mut root = T::ONE << bits.div_ceil(2);
+    loop {
+        let next = (root + n / root) >> 1;
+        if next >= root {
+            return root;
+        }
+        root = next;
+    }
+}
+
+/// `base` to the power of `exponent` modulo `modulus`
+///
+/// Panics if the modulus is zero.
+pub fn mod_pow<T: Unsigned>(base: T, mut exponent: T, modulus: T) -> T {
+    assert!(modulus != T::ZERO, "modulus should not be zero");
+    let (mut base, mut result) = (base % modulus, T::ONE % modulus);
+    while exponent > T::ZERO {
+        if exponent % (T::ONE + T::ONE) == T::ONE {
+            result = result.mul_mod(base, modulus);
+        }
+        base = base.mul_mod(base, modulus);
+        exponent = exponent >> 1;
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gcd_and_lcm() {
+        assert_eq!(6, gcd(48u64, 18));
+        assert_eq!(7, gcd(0u64, 7));
+        assert_eq!(0, gcd(0u128, 0));
+        assert_eq!(Some(36), lcm(12u64, 18));
+        assert_eq!(Some(0), lcm(0u64, 5));
+        assert_eq!(Some(6), lcm_all([2u64, 3, 6]));
+        assert_eq!(Some(1), lcm_all(Vec::<u64>::new()));
+        assert_eq!(None, lcm(u64::MAX, u64::MAX - 1));
+        let product = u64::MAX as u128 * (u64::MAX - 1) as u128;
+        assert_eq!(Some(product), lcm(u64::MAX as u128, (u64::MAX - 1) as u128));
+    }
+
+    #[test]
+    fn test_extended_gcd() {
+        for (a, b) in [(240, 46), (-240, 46), (17, 0), (0, -5), (0, 0)] {
+            let (g, x, y) = extended_gcd(a, b);
+            assert_eq!(gcd(a.unsigned_abs(), b.unsigned_abs()), g as u128);
+            assert_eq!(g, a * x + b * y);
+        }
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(Some((23, 105)), crt(&[(2, 3), (3, 5), (2, 7)]));
+        assert_eq!(Some((10, 12)), crt(&[(4, 6), (2, 4)]));
+        assert_eq!(None, crt(&[(1, 6), (2, 4)]));
+        assert_eq!(Some((0, 1)), crt(&[]));
+        assert_eq!(Some((5, 7)), crt(&[(12, 7)]));
+        let third = u64::MAX / 3;
+        assert_eq!(Some((1, u64::MAX)), crt(&[(1, third), (1, 15)]));
+        assert_eq!(Some((2 * third, u64::MAX)), crt(&[(0, third), (1, 3)]));
+        assert_eq!(None, crt(&[(0, u64::MAX), (0, u64::MAX - 1)]));
+    }
+
+    #[test]
+    fn test_isqrt() {
+        let roots: Vec<u64> = (0..=17).map(isqrt).collect();
+        let expected = [0, 1, 1, 1, 2, 2, 2, 2, 2, 3, 3, 3, 3, 3, 3, 3, 4, 4];
+        assert_eq!(expected.to_vec(), roots);
+        assert_eq!(u32::MAX as u64, isqrt(u64::MAX));
+        assert_eq!(u64::MAX as u128, isqrt(u128::MAX));
+        let square = 999_999_999_989u128 * 999_999_999_989;
+        assert_eq!(999_999_999_989, isqrt(square));
+        assert_eq!(999_999_999_988, isqrt(square - 1));
+    }
+
+    #[test]
+    fn test_mod_pow() {
+        assert_eq!(445, mod_pow(4u64, 13, 497));
+        assert_eq!(0, mod_pow(5u64, 3, 1));
+        assert_eq!(1, mod_pow(7u64, 0, 13));
+        // Fermat's little theorem with a prime that needs the full width of u128
+        let prime = u128::MAX - 158;
+        assert_eq!(1, mod_pow(3u128, prime - 1, prime));
+        let prime = u64::MAX - 58;
+        assert_eq!(1, mod_pow(2u64, prime - 1, prime));
+    }
+}
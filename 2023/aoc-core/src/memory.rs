@@ -0,0 +1,137 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Allocations made by one thread while a measured closure ran
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Allocations {
+    pub count: u64, // Allocations and reallocations
+    pub bytes: u64, // Bytes requested by them
+    pub peak: u64,  // Most bytes live at once, on top of what was live before
+}
+
+/// Global allocator that counts the allocations of every thread on its own. Installing it
+/// with `#[global_allocator]` makes `measure` return numbers.
+pub struct CountingAllocator;
+
+// Set by the first allocation through the counting allocator
+static INSTALLED: AtomicBool = AtomicBool::new(false);
+
+#[derive(Clone, Copy)]
+struct Counters {
+    count: u64,
+    bytes: u64,
+    live: i64, // Negative when memory allocated before is freed
+    peak: i64,
+}
+
+thread_local! {
+    // Constant initialization keeps the allocator from allocating to reach its own counters
+    static COUNTERS: Cell<Counters> = const {
+        Cell::new(Counters {
+            count: 0,
+            bytes: 0,
+            live: 0,
+            peak: 0,
+        })
+    };
+}
+
+fn record(allocated: usize, freed: usize) {
+    if !INSTALLED.load(Ordering::Relaxed) {
+        INSTALLED.store(true, Ordering::Relaxed);
+    }
+    // Allocations of a thread that is shutting down are not counted
+    let _ = COUNTERS.try_with(|counters| {
+        let mut current = counters.get();
+        if allocated > 0 {
+            current.count += 1;
+            current.bytes += allocated as u64;
+        }
+        current.live += allocated as i64 - freed as i64;
+        current.peak = current.peak.max(current.live);
+        counters.set(current);
+    });
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let pointer = System.alloc(layout);
+        if !pointer.is_null() {
+            record(layout.size(), 0);
+        }
+        pointer
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let pointer = System.alloc_zeroed(layout);
+        if !pointer.is_null() {
+            record(layout.size(), 0);
+        }
+        pointer
+    }
+
+    unsafe fn dealloc(&self, pointer: *mut u8, layout: Layout) {
+        System.dealloc(pointer, layout);
+        record(0, layout.size());
+    }
+
+    unsafe fn realloc(&self, pointer: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_pointer = System.realloc(pointer, layout, new_size);
+        if !new_pointer.is_null() {
+            record(new_size, layout.size());
+        }
+        new_pointer
+    }
+}
+
+/// Runs the closure and counts the allocations that the current thread made meanwhile, or
+/// `None` when the counting allocator is not installed. Measurements should not be nested,
+/// since the inner one restarts the peak of the outer one.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<Allocations>) {
+    let start = COUNTERS.with(|counters| {
+        let mut current = counters.get();
+        current.peak = current.live;
+        counters.set(current);
+        current
+    });
+    let value = f();
+    let end = COUNTERS.with(Cell::get);
+    let allocations = Allocations {
+        count: end.count - start.count,
+        bytes: end.bytes - start.bytes,
+        peak: (end.peak - start.live).max(0) as u64,
+    };
+    (
+        value,
+        INSTALLED.load(Ordering::Relaxed).then_some(allocations),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[global_allocator]
+    static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+    #[test]
+    fn test_measure() {
+        let (sum, allocations) = measure(|| {
+            let small = vec![1u8; 1000];
+            let large = vec![1u8; 4000];
+            drop(small);
+            let mut grown: Vec<u8> = Vec::with_capacity(10);
+            grown.extend([1u8; 100]);
+            large.len() + grown.len()
+        });
+        assert_eq!(4100, sum);
+        let allocations = allocations.expect("the counting allocator is installed");
+        assert_eq!(4, allocations.count);
+        assert_eq!(1000 + 4000 + 10 + 100, allocations.bytes);
+        assert_eq!(5000, allocations.peak);
+
+        let (_, nothing) = measure(|| 1 + 1);
+        assert_eq!(Some(Allocations::default()), nothing);
+    }
+}
@@ -0,0 +1,87 @@
+use crate::SpanError;
+use std::num::{IntErrorKind, ParseIntError};
+use std::str::FromStr;
+
+/// Any primitive integer type, signed or unsigned
+pub trait Integer: FromStr<Err = ParseIntError> {}
+
+impl<T: FromStr<Err = ParseIntError>> Integer for T {}
+
+/// Parses a single number, e.g. "42" or "-7"
+pub fn parse_number<T: Integer>(token: &str) -> Result<T, SpanError<'_>> {
+    token.parse().map_err(|err: ParseIntError| SpanError {
+        span: token,
+        message: match err.kind() {
+            IntErrorKind::PosOverflow | IntErrorKind::NegOverflow => "number is out of range",
+            _ => "expected a number",
+        },
+    })
+}
+
+/// Parses numbers separated by whitespace, e.g. " 41 48  83 -6"
+pub fn parse_numbers<T: Integer>(text: &str) -> Result<Vec<T>, SpanError<'_>> {
+    text.split_whitespace().map(parse_number).collect()
+}
+
+/// Parses "Label: numbers | numbers" into the label and each section of numbers
+pub fn parse_labeled_sections<T: Integer>(
+    line: &str,
+) -> Result<(&str, Vec<Vec<T>>), SpanError<'_>> {
+    let (label, sections) = line.split_once(':').ok_or(SpanError {
+        span: line,
+        message: "expected a label followed by ':'",
+    })?;
+    let sections = sections
+        .split('|')
+        .map(parse_numbers)
+        .collect::<Result<_, _>>()?;
+    Ok((label.trim(), sections))
+}
+
+/// Parses numbers with bad kerning as one number, e.g. " 7  15   30" is 71530
+pub fn parse_kerned<T: Integer>(text: &str) -> Result<T, SpanError<'_>> {
+    let digits: String = text.split_whitespace().collect();
+    parse_number(&digits).map_err(|err| SpanError {
+        span: text.trim(),
+        message: err.message,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_numbers() {
+        assert_eq!(Ok(vec![41u8, 48, 83]), parse_numbers(" 41 48  83 "));
+        assert_eq!(Ok(vec![-6i64, 0, 12]), parse_numbers("-6 0 12"));
+
+        let text = "79 14 x5 13";
+        let error = parse_numbers::<u32>(text).unwrap_err();
+        assert_eq!(("x5", "expected a number"), (error.span, error.message));
+        let error = parse_numbers::<u8>("1 256").unwrap_err();
+        assert_eq!(
+            ("256", "number is out of range"),
+            (error.span, error.message)
+        );
+    }
+
+    #[test]
+    fn test_parse_labeled_sections() {
+        let line = "Card   1: 41 48 83 | 83 86  6 31";
+        let (label, sections) = parse_labeled_sections::<u32>(line).unwrap();
+        assert_eq!("Card   1", label);
+        assert_eq!(vec![vec![41, 48, 83], vec![83, 86, 6, 31]], sections);
+
+        let error = parse_labeled_sections::<u32>("41 48 | 83").unwrap_err();
+        assert_eq!("expected a label followed by ':'", error.message);
+    }
+
+    #[test]
+    fn test_parse_kerned() {
+        assert_eq!(Ok(71530u64), parse_kerned("      7  15   30"));
+        let error = parse_kerned::<u64>(" 7  1x ").unwrap_err();
+        assert_eq!(("7  1x", "expected a number"), (error.span, error.message));
+        assert!(parse_kerned::<u64>("   ").is_err());
+    }
+}
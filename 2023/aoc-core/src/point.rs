@@ -0,0 +1,281 @@
+use crate::grid::Position;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// Point on a plane where y grows downwards, like the rows of a grid
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+/// Difference between two points
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Vector {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point { x: 0, y: 0 };
+
+    pub fn new(x: i64, y: i64) -> Self {
+        Point { x, y }
+    }
+
+    /// Steps between the points when moving only along the axes
+    pub fn manhattan_distance(self, other: Point) -> u64 {
+        (other - self).manhattan_length()
+    }
+
+    /// Grid position of the point, or `None` when it is left of or above the grid
+    pub fn position(self) -> Option<Position> {
+        Some((self.y.try_into().ok()?, self.x.try_into().ok()?))
+    }
+}
+
+impl Vector {
+    pub fn new(x: i64, y: i64) -> Self {
+        Vector { x, y }
+    }
+
+    pub fn manhattan_length(self) -> u64 {
+        self.x.unsigned_abs() + self.y.unsigned_abs()
+    }
+}
+
+impl From<Position> for Point {
+    fn from((row, col): Position) -> Self {
+        Point::new(col as i64, row as i64)
+    }
+}
+
+impl Add<Vector> for Point {
+    type Output = Point;
+
+    fn add(self, vector: Vector) -> Point {
+        Point::new(self.x + vector.x, self.y + vector.y)
+    }
+}
+
+impl AddAssign<Vector> for Point {
+    fn add_assign(&mut self, vector: Vector) {
+        *self = *self + vector;
+    }
+}
+
+impl Sub<Vector> for Point {
+    type Output = Point;
+
+    fn sub(self, vector: Vector) -> Point {
+        self + -vector
+    }
+}
+
+impl SubAssign<Vector> for Point {
+    fn sub_assign(&mut self, vector: Vector) {
+        *self = *self - vector;
+    }
+}
+
+impl Sub for Point {
+    type Output = Vector;
+
+    fn sub(self, other: Point) -> Vector {
+        Vector::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Add for Vector {
+    type Output = Vector;
+
+    fn add(self, other: Vector) -> Vector {
+        Vector::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub for Vector {
+    type Output = Vector;
+
+    fn sub(self, other: Vector) -> Vector {
+        self + -other
+    }
+}
+
+impl Neg for Vector {
+    type Output = Vector;
+
+    fn neg(self) -> Vector {
+        Vector::new(-self.x, -self.y)
+    }
+}
+
+impl Mul<i64> for Vector {
+    type Output = Vector;
+
+    fn mul(self, factor: i64) -> Vector {
+        Vector::new(self.x * factor, self.y * factor)
+    }
+}
+
+/// Direction along the axes, where north is up
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction4 {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction4 {
+    /// Clockwise from north
+    pub const ALL: [Direction4; 4] = [
+        Direction4::North,
+        Direction4::East,
+        Direction4::South,
+        Direction4::West,
+    ];
+
+    /// Quarter turn clockwise
+    pub fn turn_right(self) -> Self {
+        Direction4::ALL[(self as usize + 1) % 4]
+    }
+
+    /// Quarter turn counterclockwise
+    pub fn turn_left(self) -> Self {
+        Direction4::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn reverse(self) -> Self {
+        Direction4::ALL[(self as usize + 2) % 4]
+    }
+
+    /// Vector of one step in the direction
+    pub fn offset(self) -> Vector {
+        Direction8::from(self).offset()
+    }
+}
+
+/// Direction along the axes or diagonally, where north is up
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction8 {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction8 {
+    /// Clockwise from north
+    pub const ALL: [Direction8; 8] = [
+        Direction8::North,
+        Direction8::NorthEast,
+        Direction8::East,
+        Direction8::SouthEast,
+        Direction8::South,
+        Direction8::SouthWest,
+        Direction8::West,
+        Direction8::NorthWest,
+    ];
+
+    /// Eighth of a turn clockwise
+    pub fn turn_right(self) -> Self {
+        Direction8::ALL[(self as usize + 1) % 8]
+    }
+
+    /// Eighth of a turn counterclockwise
+    pub fn turn_left(self) -> Self {
+        Direction8::ALL[(self as usize + 7) % 8]
+    }
+
+    pub fn reverse(self) -> Self {
+        Direction8::ALL[(self as usize + 4) % 8]
+    }
+
+    /// Vector of one step in the direction
+    pub fn offset(self) -> Vector {
+        let (x, y) = match self {
+            Direction8::North => (0, -1),
+            Direction8::NorthEast => (1, -1),
+            Direction8::East => (1, 0),
+            Direction8::SouthEast => (1, 1),
+            Direction8::South => (0, 1),
+            Direction8::SouthWest => (-1, 1),
+            Direction8::West => (-1, 0),
+            Direction8::NorthWest => (-1, -1),
+        };
+        Vector::new(x, y)
+    }
+}
+
+impl From<Direction4> for Direction8 {
+    fn from(direction: Direction4) -> Self {
+        Direction8::ALL[direction as usize * 2]
+    }
+}
+
+impl From<Direction4> for Vector {
+    fn from(direction: Direction4) -> Self {
+        direction.offset()
+    }
+}
+
+impl From<Direction8> for Vector {
+    fn from(direction: Direction8) -> Self {
+        direction.offset()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let (a, b) = (Point::new(1, 6), Point::new(5, 11));
+        assert_eq!(Vector::new(4, 5), b - a);
+        assert_eq!(b, a + (b - a));
+        assert_eq!(Point::new(-3, 1), a - (b - a));
+        assert_eq!(Vector::new(-8, -10), -(b - a) * 2);
+        assert_eq!(9, a.manhattan_distance(b));
+        assert_eq!(9, b.manhattan_distance(a));
+
+        let mut point = Point::ORIGIN;
+        point += Vector::new(2, 3);
+        point -= Vector::new(0, 1);
+        assert_eq!(Some((2, 2)), point.position());
+        assert_eq!(Point::new(4, 3), Point::from((3, 4)));
+        assert_eq!(None, Point::new(-1, 3).position());
+    }
+
+    #[test]
+    fn test_directions() {
+        use Direction4::*;
+        assert_eq!(
+            [East, South, West, North],
+            Direction4::ALL.map(Direction4::turn_right)
+        );
+        assert_eq!(
+            [West, North, East, South],
+            Direction4::ALL.map(Direction4::turn_left)
+        );
+        assert_eq!(
+            [South, West, North, East],
+            Direction4::ALL.map(Direction4::reverse)
+        );
+        assert_eq!(Vector::new(0, -1), North.offset());
+        assert_eq!(Vector::new(-1, 0), Vector::from(West));
+
+        assert_eq!(Direction8::SouthEast, Direction8::East.turn_right());
+        assert_eq!(Direction8::NorthWest, Direction8::North.turn_left());
+        assert_eq!(Direction8::SouthWest, Direction8::NorthEast.reverse());
+        assert_eq!(Direction8::West, Direction8::from(West));
+        for direction in Direction8::ALL {
+            assert_eq!(-direction.offset(), direction.reverse().offset());
+            assert_eq!(direction, direction.turn_right().turn_left());
+        }
+    }
+}
@@ -0,0 +1,114 @@
+use std::ops::Range;
+
+/// Small seeded random number generator (SplitMix64), so a generated input can be reproduced
+/// from its seed
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+/// Integers that can be drawn uniformly from a range
+pub trait Uniform: Copy {
+    fn draw(rng: &mut Rng, range: Range<Self>) -> Self;
+}
+
+macro_rules! impl_uniform {
+    ($($integer:ty),*) => {
+        $(
+            impl Uniform for $integer {
+                fn draw(rng: &mut Rng, range: Range<Self>) -> Self {
+                    assert!(range.start < range.end, "cannot draw from an empty range");
+                    let span = (range.end as i128 - range.start as i128) as u64;
+                    (range.start as i128 + rng.below(span) as i128) as $integer
+                }
+            }
+        )*
+    };
+}
+
+impl_uniform!(u8, u16, u32, u64, usize, i32, i64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform number in `0..bound`
+    pub fn below(&mut self, bound: u64) -> u64 {
+        assert!(bound > 0, "bound should be positive");
+        // Rejecting the uneven tail keeps every number equally likely
+        let limit = u64::MAX - u64::MAX % bound;
+        loop {
+            let number = self.next_u64();
+            if number < limit {
+                return number % bound;
+            }
+        }
+    }
+
+    pub fn range<T: Uniform>(&mut self, range: Range<T>) -> T {
+        T::draw(self, range)
+    }
+
+    pub fn chance(&mut self, probability: f64) -> bool {
+        // The top 53 bits fill the mantissa of a number in 0..1
+        let fraction = (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64;
+        fraction < probability
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.range(0..items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for index in (1..items.len()).rev() {
+            items.swap(index, self.range(0..index + 1));
+        }
+    }
+}
+
+/// Side of a grid `scale` times the area of a grid with the given side
+pub fn scaled_side(side: usize, scale: usize) -> usize {
+    (side as f64 * (scale as f64).sqrt()).round() as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_same_seed_same_numbers() {
+        let numbers = |seed| {
+            let mut rng = Rng::new(seed);
+            (0..5).map(|_| rng.next_u64()).collect::<Vec<u64>>()
+        };
+        assert_eq!(numbers(7), numbers(7));
+        assert_ne!(numbers(7), numbers(8));
+    }
+
+    #[test]
+    fn test_range() {
+        let mut rng = Rng::new(2023);
+        let mut seen = [false; 5];
+        for _ in 0..1000 {
+            let number = rng.range(-2i64..3);
+            assert!((-2..3).contains(&number));
+            seen[(number + 2) as usize] = true;
+        }
+        assert_eq!([true; 5], seen);
+        assert_eq!(u64::MAX - 1, rng.range(u64::MAX - 1..u64::MAX));
+
+        let mut items = [1, 2, 3, 4, 5, 6];
+        rng.shuffle(&mut items);
+        items.sort();
+        assert_eq!([1, 2, 3, 4, 5, 6], items);
+    }
+}
@@ -0,0 +1,405 @@
+use crate::grid::{Grid, Position};
+use std::fmt::Write;
+
+/// Colour as red, green and blue components
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    fn hex(self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.0, self.1, self.2)
+    }
+}
+
+/// Drawing on top of the tiles of a picture
+#[derive(Debug, Clone, PartialEq)]
+pub enum Overlay {
+    /// Cells painted over, such as the tiles enclosed by a loop
+    Cells { cells: Vec<Position>, color: Rgb },
+    /// Line through the centres of the cells in order, such as the path of a loop
+    Path {
+        cells: Vec<Position>,
+        closed: bool, // Goes back from the last cell to the first
+        color: Rgb,
+    },
+    /// Straight line between two cell corners, such as a reflection line between two rows.
+    /// Corner (row, col) is the top left corner of the cell at (row, col).
+    Line {
+        from: Position,
+        to: Position,
+        color: Rgb,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageFormat {
+    Ppm,
+    Svg,
+}
+
+impl ImageFormat {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "ppm" => Some(ImageFormat::Ppm),
+            "svg" => Some(ImageFormat::Svg),
+            _ => None,
+        }
+    }
+
+    pub fn extension(self) -> &'static str {
+        match self {
+            ImageFormat::Ppm => "ppm",
+            ImageFormat::Svg => "svg",
+        }
+    }
+}
+
+/// Tile grid drawn as coloured squares, with overlays drawn over them in order
+#[derive(Debug, Clone, PartialEq)]
+pub struct Picture {
+    colors: Grid<Rgb>,
+    overlays: Vec<Overlay>,
+}
+
+impl Picture {
+    /// Colours every tile with the colour map of its tile type
+    pub fn new<T>(grid: &Grid<T>, color: impl FnMut(&T) -> Rgb) -> Self {
+        Picture {
+            colors: grid.map(color),
+            overlays: Vec::new(),
+        }
+    }
+
+    pub fn with(mut self, overlay: Overlay) -> Self {
+        self.overlays.push(overlay);
+        self
+    }
+
+    pub fn height(&self) -> usize {
+        self.colors.height()
+    }
+
+    pub fn width(&self) -> usize {
+        self.colors.width()
+    }
+
+    /// Image with each tile drawn as a square of `cell_size` pixels
+    pub fn encode(&self, format: ImageFormat, cell_size: usize) -> Vec<u8> {
+        match format {
+            ImageFormat::Ppm => self.to_ppm(cell_size),
+            ImageFormat::Svg => self.to_svg(cell_size).into_bytes(),
+        }
+    }
+
+    /// Binary PPM (P6) image
+    pub fn to_ppm(&self, cell_size: usize) -> Vec<u8> {
+        let mut canvas = Canvas::new(self.height() * cell_size, self.width() * cell_size);
+        for ((row, col), &color) in self.colors.iter() {
+            canvas.fill_cell((row, col), cell_size, color);
+        }
+
+        // Lines are a quarter of a cell thick, so that neighbouring lines stay apart
+        let thickness = (cell_size / 4).max(1);
+        let center = |(row, col): Position| {
+            (
+                row * cell_size + cell_size / 2,
+                col * cell_size + cell_size / 2,
+            )
+        };
+        for overlay in &self.overlays {
+            match overlay {
+                Overlay::Cells { cells, color } => {
+                    for &cell in cells {
+                        canvas.fill_cell(cell, cell_size, *color);
+                    }
+                }
+                Overlay::Path {
+                    cells,
+                    closed,
+                    color,
+                } => {
+                    for (from, to) in path_segments(cells, *closed) {
+                        canvas.line(center(from), center(to), thickness, *color);
+                    }
+                }
+                Overlay::Line { from, to, color } => {
+                    let corner = |(row, col): Position| (row * cell_size, col * cell_size);
+                    canvas.line(corner(*from), corner(*to), thickness, *color);
+                }
+            }
+        }
+        canvas.to_ppm()
+    }
+
+    /// SVG image, with the tiles of a row merged into one rectangle per run of one colour
+    pub fn to_svg(&self, cell_size: usize) -> String {
+        let (height, width) = (self.height() * cell_size, self.width() * cell_size);
+        let mut svg = String::new();
+        let _ = writeln!(
+            svg,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{}" height="{}" viewBox="0 0 {} {}" shape-rendering="crispEdges">"#,
+            width, height, width, height
+        );
+        let rect = |svg: &mut String, (row, col): Position, length: usize, color: Rgb| {
+            let _ = writeln!(
+                svg,
+                r#"<rect x="{}" y="{}" width="{}" height="{}" fill="{}"/>"#,
+                col * cell_size,
+                row * cell_size,
+                length * cell_size,
+                cell_size,
+                color.hex()
+            );
+        };
+        for (row, colors) in self.colors.rows().enumerate() {
+            let mut start = 0;
+            for col in 1..=colors.len() {
+                if col == colors.len() || colors[col] != colors[start] {
+                    rect(&mut svg, (row, start), col - start, colors[start]);
+                    start = col;
+                }
+            }
+        }
+
+        let thickness = (cell_size as f64 / 4.0).max(1.0);
+        let center = |(row, col): Position| {
+            let half = cell_size as f64 / 2.0;
+            let x = (col * cell_size) as f64 + half;
+            let y = (row * cell_size) as f64 + half;
+            format!("{},{}", x, y)
+        };
+        for overlay in &self.overlays {
+            match overlay {
+                Overlay::Cells { cells, color } => {
+                    for &cell in cells {
+                        rect(&mut svg, cell, 1, *color);
+                    }
+                }
+                Overlay::Path {
+                    cells,
+                    closed,
+                    color,
+                } => {
+                    let points: Vec<String> = cells.iter().map(|&cell| center(cell)).collect();
+                    let _ = writeln!(
+                        svg,
+                        r#"<{} points="{}" fill="none" stroke="{}" stroke-width="{}" stroke-linejoin="round" shape-rendering="auto"/>"#,
+                        if *closed { "polygon" } else { "polyline" },
+                        points.join(" "),
+                        color.hex(),
+                        thickness
+                    );
+                }
+                Overlay::Line { from, to, color } => {
+                    let _ = writeln!(
+                        svg,
+                        r#"<line x1="{}" y1="{}" x2="{}" y2="{}" stroke="{}" stroke-width="{}"/>"#,
+                        from.1 * cell_size,
+                        from.0 * cell_size,
+                        to.1 * cell_size,
+                        to.0 * cell_size,
+                        color.hex(),
+                        thickness
+                    );
+                }
+            }
+        }
+        svg.push_str("</svg>\n");
+        svg
+    }
+
+    /// Text with ANSI colours for the terminal. Each character shows two tiles stacked with
+    /// a half block, and path overlays colour the cells they pass through. Lines between
+    /// cells are left out, since they have no room between characters.
+    pub fn to_ansi(&self) -> String {
+        let mut colors: Grid<Rgb> = self.colors.clone();
+        for overlay in &self.overlays {
+            match overlay {
+                Overlay::Cells { cells, color } | Overlay::Path { cells, color, .. } => {
+                    for &cell in cells {
+                        colors[cell] = *color;
+                    }
+                }
+                Overlay::Line { .. } => {}
+            }
+        }
+
+        let mut text = String::new();
+        for row in (0..colors.height()).step_by(2) {
+            // Colours are only set when they change from the previous character
+            let mut previous: Option<(Rgb, Option<Rgb>)> = None;
+            for col in 0..colors.width() {
+                let (upper, lower) = (colors[(row, col)], colors.get((row + 1, col)).copied());
+                if previous.map(|(upper, _)| upper) != Some(upper) {
+                    let Rgb(red, green, blue) = upper;
+                    let _ = write!(text, "\x1b[38;2;{};{};{}m", red, green, blue);
+                }
+                if previous.map(|(_, lower)| lower) != Some(lower) {
+                    match lower {
+                        Some(Rgb(red, green, blue)) => {
+                            let _ = write!(text, "\x1b[48;2;{};{};{}m", red, green, blue);
+                        }
+                        None => text.push_str("\x1b[49m"), // Odd row count, nothing below
+                    }
+                }
+                previous = Some((upper, lower));
+                text.push('\u{2580}'); // Upper half block
+            }
+            text.push_str("\x1b[0m\n");
+        }
+        text
+    }
+}
+
+// Pairs of consecutive cells of a path
+fn path_segments(cells: &[Position], closed: bool) -> Vec<(Position, Position)> {
+    let mut segments: Vec<(Position, Position)> =
+        cells.windows(2).map(|pair| (pair[0], pair[1])).collect();
+    if let (true, Some(&first), Some(&last)) = (closed, cells.first(), cells.last()) {
+        segments.push((last, first));
+    }
+    segments
+}
+
+// Pixels of a PPM image, row by row
+struct Canvas {
+    pixels: Grid<Rgb>,
+}
+
+impl Canvas {
+    fn new(height: usize, width: usize) -> Self {
+        Canvas {
+            pixels: Grid::filled(height, width, Rgb(0, 0, 0)),
+        }
+    }
+
+    fn fill_cell(&mut self, (row, col): Position, cell_size: usize, color: Rgb) {
+        for y in row * cell_size..(row + 1) * cell_size {
+            for x in col * cell_size..(col + 1) * cell_size {
+                self.paint((y, x), color);
+            }
+        }
+    }
+
+    // Pixels outside the canvas are skipped, so lines along the edges can be drawn
+    fn paint(&mut self, position: Position, color: Rgb) {
+        if let Some(pixel) = self.pixels.get_mut(position) {
+            *pixel = color;
+        }
+    }
+
+    // Line stepped one pixel at a time, drawn as squares of `thickness` pixels
+    fn line(&mut self, from: Position, to: Position, thickness: usize, color: Rgb) {
+        let (y0, x0) = (from.0 as i64, from.1 as i64);
+        let (y1, x1) = (to.0 as i64, to.1 as i64);
+        let steps = (y1 - y0).abs().max((x1 - x0).abs()).max(1);
+        let offset = (thickness as i64 - 1) / 2;
+        for step in 0..=steps {
+            let y = y0 + (y1 - y0) * step / steps - offset;
+            let x = x0 + (x1 - x0) * step / steps - offset;
+            for dy in 0..thickness as i64 {
+                for dx in 0..thickness as i64 {
+                    if y + dy >= 0 && x + dx >= 0 {
+                        self.paint(((y + dy) as usize, (x + dx) as usize), color);
+                    }
+                }
+            }
+        }
+    }
+
+    fn to_ppm(&self) -> Vec<u8> {
+        let header = format!(
+            "P6\n{} {}\n255\n",
+            self.pixels.width(),
+            self.pixels.height()
+        );
+        let mut image = header.into_bytes();
+        for (_, &Rgb(red, green, blue)) in self.pixels.iter() {
+            image.extend([red, green, blue]);
+        }
+        image
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RED: Rgb = Rgb(255, 0, 0);
+    const BLUE: Rgb = Rgb(0, 0, 255);
+    const WHITE: Rgb = Rgb(255, 255, 255);
+
+    fn picture() -> Picture {
+        let grid = Grid::from_chars("#..\n.#.", Ok::<char, &str>).unwrap();
+        Picture::new(&grid, |&c| if c == '#' { RED } else { BLUE })
+    }
+
+    // Pixel of a 6x4 image, whose pixels follow the header
+    fn pixel(ppm: &[u8], (y, x): Position) -> Rgb {
+        let index = ppm.len() - 6 * 4 * 3 + (y * 6 + x) * 3;
+        Rgb(ppm[index], ppm[index + 1], ppm[index + 2])
+    }
+
+    #[test]
+    fn test_ppm() {
+        let picture = picture().with(Overlay::Cells {
+            cells: vec![(1, 2)],
+            color: WHITE,
+        });
+        let ppm = picture.to_ppm(2);
+        assert!(ppm.starts_with(b"P6\n6 4\n255\n"));
+        assert_eq!(b"P6\n6 4\n255\n".len() + 6 * 4 * 3, ppm.len());
+        assert_eq!(RED, pixel(&ppm, (1, 1)));
+        assert_eq!(BLUE, pixel(&ppm, (0, 2)));
+        assert_eq!(WHITE, pixel(&ppm, (3, 5)));
+
+        // Horizontal line along the corners between the two rows
+        let line = picture.with(Overlay::Line {
+            from: (1, 0),
+            to: (1, 3),
+            color: WHITE,
+        });
+        let ppm = line.to_ppm(2);
+        assert!((0..6).all(|x| pixel(&ppm, (2, x)) == WHITE));
+        assert_eq!(RED, pixel(&ppm, (3, 2)));
+    }
+
+    #[test]
+    fn test_svg() {
+        let svg = picture()
+            .with(Overlay::Path {
+                cells: vec![(0, 0), (1, 1), (0, 2)],
+                closed: false,
+                color: WHITE,
+            })
+            .to_svg(10);
+        assert!(
+            svg.starts_with(r#"<svg xmlns="http://www.w3.org/2000/svg" width="30" height="20""#)
+        );
+        // Neighbouring tiles of the same colour are one rectangle
+        assert!(svg.contains(r##"<rect x="10" y="0" width="20" height="10" fill="#0000ff"/>"##));
+        assert_eq!(5, svg.matches("<rect").count());
+        assert!(svg.contains(r#"<polyline points="5,5 15,15 25,5""#));
+        assert!(svg.ends_with("</svg>\n"));
+    }
+
+    #[test]
+    fn test_ansi() {
+        let ansi = picture()
+            .with(Overlay::Path {
+                cells: vec![(0, 2)],
+                closed: false,
+                color: WHITE,
+            })
+            .to_ansi();
+        let upper_red_lower_blue = "\x1b[38;2;255;0;0m\x1b[48;2;0;0;255m\u{2580}";
+        assert!(ansi.starts_with(upper_red_lower_blue));
+        assert!(ansi.ends_with("\x1b[38;2;255;255;255m\x1b[48;2;0;0;255m\u{2580}\x1b[0m\n"));
+        assert_eq!(7, ansi.matches("\x1b[").count());
+
+        // Unchanged colours are not repeated
+        let plain = Picture::new(&Grid::filled(2, 3, ()), |_| RED).to_ansi();
+        assert_eq!(3, plain.matches("\x1b[").count());
+        assert_eq!(1, ansi.lines().count());
+    }
+}
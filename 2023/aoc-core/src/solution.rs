@@ -0,0 +1,160 @@
+use crate::memory::{self, Allocations};
+use crate::{Animation, Answer, ParseError, Picture, Rng};
+use std::fmt;
+use std::time::{Duration, Instant};
+
+/// A puzzle solution split into a shared parsing step and the two puzzle parts
+pub trait Solution {
+    const YEAR: u16;
+    const DAY: u8;
+
+    /// Parsed puzzle input, which may borrow from the raw input text
+    type Input<'a>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError>;
+    fn part1(&self, input: &Self::Input<'_>) -> Answer;
+    fn part2(&self, input: &Self::Input<'_>) -> Answer;
+
+    /// Generates a random input about `scale` times the size of the official one, for days
+    /// that have a generator
+    fn generate(&self, _rng: &mut Rng, _scale: usize) -> Option<Generated> {
+        None
+    }
+
+    /// Draws the grid that the part works on, for days that have something to show
+    fn render(&self, _input: &Self::Input<'_>, _part: Part) -> Option<Picture> {
+        None
+    }
+
+    /// Plays the part step by step, returning false for days without a simulation to watch
+    fn animate(&self, _input: &Self::Input<'_>, _part: Part, _animation: &mut Animation) -> bool {
+        false
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+/// Answer to one part, with the time spent parsing the input and solving the part
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Timed {
+    pub answer: Answer,
+    pub parse: Duration,
+    pub solve: Duration,
+    pub allocations: Option<Allocations>, // Of parsing and solving, when they are counted
+}
+
+/// Randomly generated input, with the answers that are known from how it was built
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Generated {
+    pub input: String,
+    pub answers: [Option<Answer>; 2], // Answers of part 1 and part 2
+}
+
+impl Generated {
+    pub fn answer(&self, part: Part) -> Option<&Answer> {
+        self.answers[part.number() as usize - 1].as_ref()
+    }
+}
+
+/// Object safe view of a `Solution`, so that days with different input types can be run
+/// through the same registry
+pub trait Puzzle: Sync {
+    fn year(&self) -> u16;
+    fn day(&self) -> u8;
+
+    /// Solves one part, timing the parsing and the solving separately
+    fn solve_timed(&self, input: &str, part: Part) -> Result<Timed, ParseError>;
+
+    fn solve(&self, input: &str, part: Part) -> Result<Answer, ParseError> {
+        self.solve_timed(input, part).map(|timed| timed.answer)
+    }
+
+    /// Generates the same input for the same seed and scale
+    fn generate(&self, seed: u64, scale: usize) -> Option<Generated>;
+
+    /// Parses the input and draws the part, if the day has a picture of it
+    fn render(&self, input: &str, part: Part) -> Result<Option<Picture>, ParseError>;
+
+    /// Parses the input and plays the part, if the day has a simulation to watch
+    fn animate(
+        &self,
+        input: &str,
+        part: Part,
+        animation: &mut Animation,
+    ) -> Result<bool, ParseError>;
+}
+
+impl<S: Solution + Sync> Puzzle for S {
+    fn year(&self) -> u16 {
+        S::YEAR
+    }
+
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn solve_timed(&self, input: &str, part: Part) -> Result<Timed, ParseError> {
+        let (timed, allocations) = memory::measure(|| {
+            let start = Instant::now();
+            let input = self.parse(input)?;
+            let parsed = Instant::now();
+            let answer = match part {
+                Part::One => self.part1(&input),
+                Part::Two => self.part2(&input),
+            };
+            Ok(Timed {
+                answer,
+                parse: parsed - start,
+                solve: parsed.elapsed(),
+                allocations: None,
+            })
+        });
+        timed.map(|timed| Timed {
+            allocations,
+            ..timed
+        })
+    }
+
+    fn generate(&self, seed: u64, scale: usize) -> Option<Generated> {
+        Solution::generate(self, &mut Rng::new(seed), scale)
+    }
+
+    fn render(&self, input: &str, part: Part) -> Result<Option<Picture>, ParseError> {
+        Ok(Solution::render(self, &self.parse(input)?, part))
+    }
+
+    fn animate(
+        &self,
+        input: &str,
+        part: Part,
+        animation: &mut Animation,
+    ) -> Result<bool, ParseError> {
+        Ok(Solution::animate(
+            self,
+            &self.parse(input)?,
+            part,
+            animation,
+        ))
+    }
+}
@@ -0,0 +1,55 @@
+use std::fs;
+use std::ops::Deref;
+use std::path::{Path, PathBuf};
+
+/// Directory under the system temporary directory that is deleted with everything in it
+/// when dropped, for tests that work with files
+pub struct TempDir {
+    path: PathBuf,
+}
+
+impl TempDir {
+    /// Creates an empty directory, named after `name` and the process so that tests running
+    /// at the same time do not share it
+    pub fn new(name: &str) -> Self {
+        let path = std::env::temp_dir().join(format!("aoc-{}-{}", name, std::process::id()));
+        // Left over by an earlier run of the same process id that did not clean up
+        let _ = fs::remove_dir_all(&path);
+        fs::create_dir_all(&path)
+            .unwrap_or_else(|err| panic!("cannot create {}: {}", path.display(), err));
+        TempDir { path }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Deref for TempDir {
+    type Target = Path;
+
+    fn deref(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.path);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_temp_dir() {
+        let dir = TempDir::new("temp");
+        fs::write(dir.join("file.txt"), "text").unwrap();
+        let path = dir.path().to_path_buf();
+        assert!(path.join("file.txt").is_file());
+        drop(dir);
+        assert!(!path.exists());
+    }
+}
@@ -0,0 +1,192 @@
+use std::cell::RefCell;
+use std::fmt;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+// Threads that are capturing events. While none are, emitting an event is a single load.
+static CAPTURING: AtomicUsize = AtomicUsize::new(0);
+
+thread_local! {
+    static SINK: RefCell<Option<Sink>> = const { RefCell::new(None) };
+}
+
+struct Sink {
+    level: Level,
+    entries: Vec<Entry>,
+}
+
+/// How detailed an event is, from the most to the least important
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Level {
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl Level {
+    pub const ALL: [Level; 5] = [
+        Level::Error,
+        Level::Warn,
+        Level::Info,
+        Level::Debug,
+        Level::Trace,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Level::Error => "error",
+            Level::Warn => "warn",
+            Level::Info => "info",
+            Level::Debug => "debug",
+            Level::Trace => "trace",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Level::ALL.into_iter().find(|level| level.name() == name)
+    }
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Something a solution reports about its progress. The message is only formatted when
+/// the event is captured.
+pub trait Event: fmt::Display {
+    const LEVEL: Level;
+}
+
+/// Captured event
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    pub level: Level,
+    pub message: String,
+}
+
+impl fmt::Display for Entry {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[{}] {}", self.level, self.message)
+    }
+}
+
+/// Reports the event to the capture running on this thread, if there is one
+#[inline]
+pub fn emit<E: Event>(event: E) {
+    if CAPTURING.load(Ordering::Relaxed) > 0 {
+        record(E::LEVEL, &event);
+    }
+}
+
+#[cold]
+fn record(level: Level, event: &dyn fmt::Display) {
+    SINK.with_borrow_mut(|sink| match sink {
+        Some(sink) if level <= sink.level => sink.entries.push(Entry {
+            level,
+            message: event.to_string(),
+        }),
+        _ => {}
+    });
+}
+
+/// Runs the closure and collects the events it emits on this thread up to the given level
+pub fn capture<T>(level: Level, f: impl FnOnce() -> T) -> (T, Vec<Entry>) {
+    // Restores the previous capture even when the closure panics
+    struct Guard(Option<Sink>);
+
+    impl Drop for Guard {
+        fn drop(&mut self) {
+            SINK.set(self.0.take());
+            CAPTURING.fetch_sub(1, Ordering::Relaxed);
+        }
+    }
+
+    let sink = Sink {
+        level,
+        entries: Vec::new(),
+    };
+    CAPTURING.fetch_add(1, Ordering::Relaxed);
+    let guard = Guard(SINK.replace(Some(sink)));
+    let value = f();
+    let sink = SINK.take().expect("the capture should still be installed");
+    drop(guard);
+    (value, sink.entries)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Visited(u32);
+
+    impl fmt::Display for Visited {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "visited {}", self.0)
+        }
+    }
+
+    impl Event for Visited {
+        const LEVEL: Level = Level::Debug;
+    }
+
+    struct Stuck;
+
+    impl fmt::Display for Stuck {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("stuck")
+        }
+    }
+
+    impl Event for Stuck {
+        const LEVEL: Level = Level::Warn;
+    }
+
+    #[test]
+    fn test_capture() {
+        emit(Stuck); // Nothing is capturing, so the event is dropped
+        let (sum, entries) = capture(Level::Debug, || {
+            (1..=3)
+                .inspect(|&n| emit(Visited(n)))
+                .chain(std::iter::once(0).inspect(|_| emit(Stuck)))
+                .sum::<u32>()
+        });
+        assert_eq!(6, sum);
+        let messages: Vec<String> = entries.iter().map(Entry::to_string).collect();
+        assert_eq!(
+            vec![
+                "[debug] visited 1",
+                "[debug] visited 2",
+                "[debug] visited 3",
+                "[warn] stuck"
+            ],
+            messages
+        );
+
+        // Less important events than the level are left out
+        let ((), entries) = capture(Level::Info, || {
+            emit(Visited(1));
+            emit(Stuck);
+        });
+        assert_eq!(
+            vec![Level::Warn],
+            entries.iter().map(|entry| entry.level).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_nested_capture() {
+        let ((), outer) = capture(Level::Trace, || {
+            emit(Visited(1));
+            let ((), inner) = capture(Level::Trace, || emit(Visited(2)));
+            assert_eq!(1, inner.len());
+            emit(Visited(3));
+        });
+        let messages: Vec<&str> = outer.iter().map(|entry| entry.message.as_str()).collect();
+        assert_eq!(vec!["visited 1", "visited 3"], messages);
+        assert_eq!(Some(Level::Debug), Level::from_name("debug"));
+        assert_eq!(None, Level::from_name("verbose"));
+    }
+}
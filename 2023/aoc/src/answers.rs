@@ -0,0 +1,110 @@
+use crate::records::{Fields, Records};
+use aoc_core::{Answer, Part};
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// Registry of confirmed answers in the workspace directory
+pub const ANSWERS_FILE: &str = "answers.txt";
+
+/// Confirmed answers by year, day and part
+pub type Answers = Records<Confirmed>;
+
+/// Answer confirmed for a part
+#[derive(Debug, PartialEq)]
+pub struct Confirmed(String);
+
+impl Fields for Confirmed {
+    const HEADER: &'static str = "\
+# Confirmed answers for my puzzle inputs, checked by `aoc verify`
+# <year> <day> <part> <answer>
+";
+    const LAYOUT: &'static str = "<answer>";
+
+    fn parse(fields: &str) -> Option<Self> {
+        (!fields.is_empty()).then(|| Confirmed(fields.to_string()))
+    }
+}
+
+impl fmt::Display for Confirmed {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl Answers {
+    pub fn expected(&self, year: u16, day: u8, part: Part) -> Option<&str> {
+        let confirmed = self.entries(year, day, part).last()?;
+        Some(&confirmed.0)
+    }
+
+    pub fn insert(&mut self, year: u16, day: u8, part: Part, answer: &Answer) {
+        self.set(year, day, part, Confirmed(answer.to_string()));
+    }
+}
+
+pub fn answers_path(workspace: &Path) -> PathBuf {
+    workspace.join(ANSWERS_FILE)
+}
+
+/// Outcome of checking an answer against the registry
+#[derive(Debug, PartialEq)]
+pub enum Verification {
+    Pass,
+    Fail { expected: String },
+    Missing, // No confirmed answer to check against
+}
+
+impl Verification {
+    pub fn check(expected: Option<&str>, answer: &Answer) -> Self {
+        match expected {
+            Some(expected) if *expected == answer.to_string() => Verification::Pass,
+            Some(expected) => Verification::Fail {
+                expected: expected.to_string(),
+            },
+            None => Verification::Missing,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_display() {
+        let text = "# comment\n\n2023 6 2 71503\n2023 06 1 288\n2022 25 1 2=-1=0\n";
+        let answers = Answers::parse(text).unwrap();
+        assert_eq!(Some("288"), answers.expected(2023, 6, Part::One));
+        assert_eq!(Some("2=-1=0"), answers.expected(2022, 25, Part::One));
+        assert_eq!(None, answers.expected(2023, 7, Part::One));
+
+        let expected = format!(
+            "{}2022 25 1 2=-1=0\n2023 06 1 288\n2023 06 2 71503\n",
+            Confirmed::HEADER
+        );
+        assert_eq!(expected, answers.to_string());
+        assert_eq!(answers, Answers::parse(&expected).unwrap());
+
+        assert!(Answers::parse("2023 06 3 1").is_err());
+        assert!(Answers::parse("2023 06 1").is_err());
+    }
+
+    #[test]
+    fn test_check() {
+        let mut answers = Answers::default();
+        answers.insert(2023, 6, Part::One, &Answer::from(288u32));
+        let expected = answers.expected(2023, 6, Part::One);
+        assert_eq!(
+            Verification::Pass,
+            Verification::check(expected, &288u64.into())
+        );
+        let fail = Verification::Fail {
+            expected: "288".to_string(),
+        };
+        assert_eq!(fail, Verification::check(expected, &Answer::Unsolved));
+        assert_eq!(
+            Verification::Missing,
+            Verification::check(None, &288u64.into())
+        );
+    }
+}
@@ -0,0 +1,684 @@
+use crate::bench::{DEFAULT_RUNS, DEFAULT_WARMUP};
+use crate::report::{Report, ReportFormat};
+use aoc_core::trace::Level;
+use aoc_core::{ImageFormat, Part, StepFilter};
+use std::path::PathBuf;
+use std::time::Duration;
+
+pub const DEFAULT_YEAR: u16 = 2023;
+pub const DEFAULT_SEED: u64 = 1;
+pub const DEFAULT_SCALE: usize = 10; // Times the size of the official input
+pub const DEFAULT_DELAY: Duration = Duration::from_millis(50); // Between animation frames
+
+pub const USAGE: &str = "\
+Usage: aoc run <day|all> [part|all] [--year <year>] [--input <path>] [--render <dir>] [--format <svg|ppm>]
+                                    [--report <path.json|path.csv>] [--jobs <n>] [--timeout <secs>]
+                                    [--trace <path>] [--trace-level <level>] [--trace-day <day>]...
+       aoc bench <day|all> [part|all] [--year <year>] [--input <path>] [--warmup <n>] [--runs <n>]
+       aoc verify [day|all] [--year <year>] [--record]
+       aoc new <day>
+       aoc fetch <day> [--year <year>]
+       aoc submit <day> <part> [--year <year>]
+       aoc generate <day> [--year <year>] [--seed <n>] [--scale <n>]
+       aoc animate <day> <part> [--year <year>] [--input <path>] [--delay <ms>] [--steps <kind>] [--every <n>]";
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Run {
+        year: u16,
+        day: Option<u8>, // None runs every registered day
+        parts: Vec<Part>,
+        input: Option<PathBuf>,
+        render: Option<Render>, // Pictures of the days that can draw one
+        report: Option<Report>,
+        jobs: Option<usize>,       // Parts solved at once, None for one per CPU
+        timeout: Option<Duration>, // Wall-clock limit for each part
+        trace: Option<Trace>,
+    },
+    Bench {
+        year: u16,
+        day: Option<u8>,
+        parts: Vec<Part>,
+        input: Option<PathBuf>,
+        warmup: usize,
+        runs: usize,
+    },
+    Verify {
+        year: u16,
+        day: Option<u8>,
+        record: bool, // Store answers of parts that have no confirmed answer yet
+    },
+    New {
+        day: u8,
+    },
+    Fetch {
+        year: u16,
+        day: u8,
+    },
+    Submit {
+        year: u16,
+        day: u8,
+        part: Part,
+    },
+    Generate {
+        year: u16,
+        day: u8,
+        seed: u64,
+        scale: usize,
+    },
+    Animate {
+        year: u16,
+        day: u8,
+        part: Part,
+        input: Option<PathBuf>,
+        delay: Duration,
+        filter: StepFilter,
+    },
+    Help,
+}
+
+/// Where `aoc run` writes pictures and in which format
+#[derive(Debug, PartialEq)]
+pub struct Render {
+    pub dir: PathBuf,
+    pub format: ImageFormat,
+}
+
+/// Where `aoc run` writes the events that solutions emit, and which ones
+#[derive(Debug, PartialEq)]
+pub struct Trace {
+    pub path: PathBuf,
+    pub level: Level,  // Least important level that is written
+    pub days: Vec<u8>, // Days that are traced, empty for every day that runs
+}
+
+impl Trace {
+    pub fn traces(&self, day: u8) -> bool {
+        self.days.is_empty() || self.days.contains(&day)
+    }
+}
+
+pub fn parse<I: IntoIterator<Item = String>>(args: I) -> Result<Command, String> {
+    let mut args = args.into_iter();
+    match args.next().as_deref() {
+        Some("run") => parse_run(args, false),
+        Some("bench") => parse_run(args, true),
+        Some("verify") => parse_verify(args),
+        Some("new") => parse_new(args),
+        Some("fetch") => parse_fetch(args),
+        Some("submit") => parse_submit(args),
+        Some("generate") => parse_generate(args),
+        Some("animate") => parse_animate(args),
+        Some("help" | "-h" | "--help") | None => Ok(Command::Help),
+        Some(command) => Err(format!("unknown command '{}'", command)),
+    }
+}
+
+// Running and benchmarking select days the same way, only benchmarks take run counts
+fn parse_run(args: impl Iterator<Item = String>, bench: bool) -> Result<Command, String> {
+    let mut year = DEFAULT_YEAR;
+    let mut input: Option<PathBuf> = None;
+    let mut warmup = DEFAULT_WARMUP;
+    let mut runs = DEFAULT_RUNS;
+    let mut render_dir: Option<PathBuf> = None;
+    let mut format: Option<ImageFormat> = None;
+    let mut report: Option<Report> = None;
+    let mut jobs: Option<usize> = None;
+    let mut timeout: Option<Duration> = None;
+    let mut trace_path: Option<PathBuf> = None;
+    let mut trace_level: Option<Level> = None;
+    let mut trace_days: Vec<u8> = Vec::new();
+    let mut positional: Vec<String> = Vec::new();
+    let mut args = args.peekable();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--year" => year = parse_year(args.next())?,
+            "--input" => input = Some(args.next().ok_or("missing value for --input")?.into()),
+            "--warmup" if bench => warmup = parse_count("--warmup", args.next(), 0)?,
+            "--runs" if bench => runs = parse_count("--runs", args.next(), 1)?,
+            "--render" if !bench => {
+                render_dir = Some(args.next().ok_or("missing value for --render")?.into())
+            }
+            "--format" if !bench => format = Some(parse_format(args.next())?),
+            "--report" if !bench => report = Some(parse_report(args.next())?),
+            "--jobs" if !bench => jobs = Some(parse_count("--jobs", args.next(), 1)?),
+            "--timeout" if !bench => {
+                let seconds = parse_count("--timeout", args.next(), 1)?;
+                timeout = Some(Duration::from_secs(seconds as u64));
+            }
+            "--trace" if !bench => {
+                trace_path = Some(args.next().ok_or("missing value for --trace")?.into())
+            }
+            "--trace-level" if !bench => trace_level = Some(parse_level(args.next())?),
+            "--trace-day" if !bench => {
+                let day = args.next().ok_or("missing value for --trace-day")?;
+                match parse_day(&day)? {
+                    Some(day) => trace_days.push(day),
+                    None => return Err("--trace-day should be a single day".to_string()),
+                }
+            }
+            _ if arg.starts_with("--") => return Err(format!("unknown option '{}'", arg)),
+            _ => positional.push(arg),
+        }
+    }
+
+    let mut positional = positional.into_iter();
+    let day = match positional.next() {
+        Some(day) => parse_day(&day)?,
+        None => return Err("missing day".to_string()),
+    };
+    let parts = match positional.next() {
+        Some(part) => parse_parts(&part)?,
+        None => Part::ALL.to_vec(),
+    };
+    if let Some(extra) = positional.next() {
+        return Err(format!("unexpected argument '{}'", extra));
+    }
+    if input.is_some() && day.is_none() {
+        return Err("--input can only be used when running a single day".to_string());
+    }
+    let render = match (render_dir, format) {
+        (Some(dir), format) => Some(Render {
+            dir,
+            format: format.unwrap_or(ImageFormat::Svg),
+        }),
+        (None, Some(_)) => return Err("--format can only be used with --render".to_string()),
+        (None, None) => None,
+    };
+    let trace = match trace_path {
+        Some(path) => Some(Trace {
+            path,
+            level: trace_level.unwrap_or(Level::Trace),
+            days: trace_days,
+        }),
+        None if trace_level.is_some() || !trace_days.is_empty() => {
+            return Err("--trace-level and --trace-day can only be used with --trace".to_string())
+        }
+        None => None,
+    };
+    if let (Some(day), Some(trace)) = (day, &trace) {
+        if !trace.traces(day) {
+            return Err(format!(
+                "--trace-day selects a day other than day {:02}",
+                day
+            ));
+        }
+    }
+    if bench {
+        return Ok(Command::Bench {
+            year,
+            day,
+            parts,
+            input,
+            warmup,
+            runs,
+        });
+    }
+    Ok(Command::Run {
+        year,
+        day,
+        parts,
+        input,
+        render,
+        report,
+        jobs,
+        timeout,
+        trace,
+    })
+}
+
+fn parse_report(path: Option<String>) -> Result<Report, String> {
+    let path = PathBuf::from(path.ok_or("missing value for --report")?);
+    match ReportFormat::from_path(&path) {
+        Some(format) => Ok(Report { path, format }),
+        None => Err(format!(
+            "report '{}' should be a .json or .csv file",
+            path.display()
+        )),
+    }
+}
+
+fn parse_level(level: Option<String>) -> Result<Level, String> {
+    let level = level.ok_or("missing value for --trace-level")?;
+    Level::from_name(&level).ok_or_else(|| {
+        format!(
+            "invalid trace level '{}', expected error, warn, info, debug or trace",
+            level
+        )
+    })
+}
+
+fn parse_format(format: Option<String>) -> Result<ImageFormat, String> {
+    let format = format.ok_or("missing value for --format")?;
+    ImageFormat::from_name(&format)
+        .ok_or_else(|| format!("invalid format '{}', expected svg or ppm", format))
+}
+
+fn parse_count(option: &str, count: Option<String>, min: usize) -> Result<usize, String> {
+    let count = count.ok_or_else(|| format!("missing value for {}", option))?;
+    match count.parse() {
+        Ok(number) if number >= min => Ok(number),
+        _ => Err(format!(
+            "invalid {} '{}', expected at least {}",
+            option, count, min
+        )),
+    }
+}
+
+fn parse_verify(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let mut year = DEFAULT_YEAR;
+    let mut day = None;
+    let mut record = false;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--year" => year = parse_year(args.next())?,
+            "--record" => record = true,
+            _ if arg.starts_with("--") => return Err(format!("unknown option '{}'", arg)),
+            _ if day.is_none() => day = Some(parse_day(&arg)?),
+            _ => return Err(format!("unexpected argument '{}'", arg)),
+        }
+    }
+    Ok(Command::Verify {
+        year,
+        day: day.flatten(), // Every day by default
+        record,
+    })
+}
+
+fn parse_new(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let day = match args.next() {
+        Some(day) => parse_day(&day)?.ok_or("a new day needs a number, not 'all'")?,
+        None => return Err("missing day".to_string()),
+    };
+    if let Some(extra) = args.next() {
+        return Err(format!("unexpected argument '{}'", extra));
+    }
+    Ok(Command::New { day })
+}
+
+fn parse_fetch(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let mut year = DEFAULT_YEAR;
+    let mut day = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--year" => year = parse_year(args.next())?,
+            _ if arg.starts_with("--") => return Err(format!("unknown option '{}'", arg)),
+            _ if day.is_none() => {
+                day = Some(parse_day(&arg)?.ok_or("fetch needs a day number, not 'all'")?)
+            }
+            _ => return Err(format!("unexpected argument '{}'", arg)),
+        }
+    }
+    let day = day.ok_or("missing day")?;
+    Ok(Command::Fetch { year, day })
+}
+
+// Answers are submitted one part at a time
+fn parse_submit(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let mut year = DEFAULT_YEAR;
+    let mut positional: Vec<String> = Vec::new();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--year" => year = parse_year(args.next())?,
+            _ if arg.starts_with("--") => return Err(format!("unknown option '{}'", arg)),
+            _ => positional.push(arg),
+        }
+    }
+    let [day, part] = <[String; 2]>::try_from(positional)
+        .map_err(|_| "expected a day and a part to submit".to_string())?;
+    let day = parse_day(&day)?.ok_or("submit needs a day number, not 'all'")?;
+    let part = match parse_parts(&part)?.as_slice() {
+        [part] => *part,
+        _ => return Err("submit needs a part number, not 'all'".to_string()),
+    };
+    Ok(Command::Submit { year, day, part })
+}
+
+fn parse_generate(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let mut year = DEFAULT_YEAR;
+    let mut seed = DEFAULT_SEED;
+    let mut scale = DEFAULT_SCALE;
+    let mut day = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--year" => year = parse_year(args.next())?,
+            "--seed" => {
+                let value = args.next().ok_or("missing value for --seed")?;
+                seed = value
+                    .parse()
+                    .map_err(|_| format!("invalid --seed '{}'", value))?;
+            }
+            "--scale" => scale = parse_count("--scale", args.next(), 1)?,
+            _ if arg.starts_with("--") => return Err(format!("unknown option '{}'", arg)),
+            _ if day.is_none() => {
+                day = Some(parse_day(&arg)?.ok_or("generate needs a day number, not 'all'")?)
+            }
+            _ => return Err(format!("unexpected argument '{}'", arg)),
+        }
+    }
+    let day = day.ok_or("missing day")?;
+    Ok(Command::Generate {
+        year,
+        day,
+        seed,
+        scale,
+    })
+}
+
+fn parse_animate(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let mut year = DEFAULT_YEAR;
+    let mut input: Option<PathBuf> = None;
+    let mut delay = DEFAULT_DELAY;
+    let mut filter = StepFilter::default();
+    let mut positional: Vec<String> = Vec::new();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--year" => year = parse_year(args.next())?,
+            "--input" => input = Some(args.next().ok_or("missing value for --input")?.into()),
+            "--delay" => {
+                delay = Duration::from_millis(parse_count("--delay", args.next(), 0)? as u64)
+            }
+            "--steps" => filter.kind = Some(args.next().ok_or("missing value for --steps")?),
+            "--every" => filter.every = parse_count("--every", args.next(), 1)?,
+            _ if arg.starts_with("--") => return Err(format!("unknown option '{}'", arg)),
+            _ => positional.push(arg),
+        }
+    }
+    let [day, part] = <[String; 2]>::try_from(positional)
+        .map_err(|_| "expected a day and a part to animate".to_string())?;
+    let day = parse_day(&day)?.ok_or("animate needs a day number, not 'all'")?;
+    let part = match parse_parts(&part)?.as_slice() {
+        [part] => *part,
+        _ => return Err("animate needs a part number, not 'all'".to_string()),
+    };
+    Ok(Command::Animate {
+        year,
+        day,
+        part,
+        input,
+        delay,
+        filter,
+    })
+}
+
+fn parse_year(year: Option<String>) -> Result<u16, String> {
+    let year = year.ok_or("missing value for --year")?;
+    year.parse().map_err(|_| format!("invalid year '{}'", year))
+}
+
+fn parse_day(day: &str) -> Result<Option<u8>, String> {
+    if day == "all" {
+        return Ok(None);
+    }
+    match day.parse() {
+        Ok(number @ 1..=25) => Ok(Some(number)),
+        _ => Err(format!("invalid day '{}', expected 1-25 or 'all'", day)),
+    }
+}
+
+fn parse_parts(part: &str) -> Result<Vec<Part>, String> {
+    match part {
+        "1" => Ok(vec![Part::One]),
+        "2" => Ok(vec![Part::Two]),
+        "all" => Ok(Part::ALL.to_vec()),
+        _ => Err(format!("invalid part '{}', expected 1, 2 or 'all'", part)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_str(args: &str) -> Result<Command, String> {
+        parse(args.split_whitespace().map(String::from))
+    }
+
+    #[test]
+    fn test_parse_run() {
+        let command = Command::Run {
+            year: 2023,
+            day: Some(5),
+            parts: vec![Part::Two],
+            input: Some(PathBuf::from("example.txt")),
+            render: None,
+            report: None,
+            jobs: None,
+            timeout: None,
+            trace: None,
+        };
+        assert_eq!(Ok(command), parse_str("run 05 2 --input example.txt"));
+    }
+
+    #[test]
+    fn test_parse_run_all() {
+        let command = Command::Run {
+            year: 2022,
+            day: None,
+            parts: vec![Part::One, Part::Two],
+            input: None,
+            render: None,
+            report: None,
+            jobs: None,
+            timeout: None,
+            trace: None,
+        };
+        assert_eq!(Ok(command), parse_str("run all --year 2022"));
+    }
+
+    #[test]
+    fn test_parse_run_render() {
+        let command = |format| Command::Run {
+            year: 2023,
+            day: Some(10),
+            parts: vec![Part::One, Part::Two],
+            input: None,
+            render: Some(Render {
+                dir: PathBuf::from("pictures"),
+                format,
+            }),
+            report: None,
+            jobs: None,
+            timeout: None,
+            trace: None,
+        };
+        assert_eq!(
+            Ok(command(ImageFormat::Svg)),
+            parse_str("run 10 --render pictures")
+        );
+        assert_eq!(
+            Ok(command(ImageFormat::Ppm)),
+            parse_str("run 10 --format ppm --render pictures")
+        );
+        assert!(parse_str("run 10 --render pictures --format png").is_err());
+        assert!(parse_str("run 10 --format svg").is_err());
+        assert!(parse_str("bench 10 --render pictures").is_err());
+    }
+
+    #[test]
+    fn test_parse_run_report() {
+        let command = Command::Run {
+            year: 2023,
+            day: None,
+            parts: vec![Part::One, Part::Two],
+            input: None,
+            render: None,
+            report: Some(Report {
+                path: PathBuf::from("reports/run.csv"),
+                format: ReportFormat::Csv,
+            }),
+            jobs: None,
+            timeout: None,
+            trace: None,
+        };
+        assert_eq!(Ok(command), parse_str("run all --report reports/run.csv"));
+        assert!(parse_str("run all --report run.txt").is_err());
+        assert!(parse_str("bench all --report run.json").is_err());
+    }
+
+    #[test]
+    fn test_parse_run_parallel() {
+        let command = Command::Run {
+            year: 2023,
+            day: None,
+            parts: vec![Part::Two],
+            input: None,
+            render: None,
+            report: None,
+            jobs: Some(4),
+            timeout: Some(Duration::from_secs(30)),
+            trace: None,
+        };
+        assert_eq!(Ok(command), parse_str("run all 2 --jobs 4 --timeout 30"));
+        assert!(parse_str("run all --jobs 0").is_err());
+        assert!(parse_str("run all --timeout 0").is_err());
+        assert!(parse_str("bench all --timeout 30").is_err());
+    }
+
+    #[test]
+    fn test_parse_run_trace() {
+        let command = |day, level, days| Command::Run {
+            year: 2023,
+            day,
+            parts: vec![Part::One, Part::Two],
+            input: None,
+            render: None,
+            report: None,
+            jobs: None,
+            timeout: None,
+            trace: Some(Trace {
+                path: PathBuf::from("trace.log"),
+                level,
+                days,
+            }),
+        };
+        assert_eq!(
+            Ok(command(Some(2), Level::Trace, vec![])),
+            parse_str("run 2 --trace trace.log")
+        );
+        assert_eq!(
+            Ok(command(None, Level::Info, vec![2, 4])),
+            parse_str("run all --trace trace.log --trace-level info --trace-day 2 --trace-day 04")
+        );
+        assert!(parse_str("run 2 --trace trace.log --trace-day 4").is_err());
+        assert!(parse_str("run all --trace trace.log --trace-day all").is_err());
+        assert!(parse_str("run all --trace trace.log --trace-level verbose").is_err());
+        assert!(parse_str("run all --trace-level info").is_err());
+        assert!(parse_str("bench 2 --trace trace.log").is_err());
+    }
+
+    #[test]
+    fn test_parse_bench() {
+        let command = Command::Bench {
+            year: 2023,
+            day: None,
+            parts: vec![Part::One],
+            input: None,
+            warmup: 0,
+            runs: 3,
+        };
+        assert_eq!(Ok(command), parse_str("bench all 1 --warmup 0 --runs 3"));
+        assert!(parse_str("bench 1 --runs 0").is_err());
+        assert!(parse_str("run 1 --runs 3").is_err());
+    }
+
+    #[test]
+    fn test_parse_verify() {
+        let all = Command::Verify {
+            year: 2023,
+            day: None,
+            record: false,
+        };
+        assert_eq!(Ok(all), parse_str("verify"));
+        let day = Command::Verify {
+            year: 2022,
+            day: Some(7),
+            record: true,
+        };
+        assert_eq!(Ok(day), parse_str("verify --year 2022 7 --record"));
+        assert!(parse_str("verify 7 8").is_err());
+    }
+
+    #[test]
+    fn test_parse_new() {
+        assert_eq!(Ok(Command::New { day: 16 }), parse_str("new 16"));
+        assert!(parse_str("new all").is_err());
+        assert!(parse_str("new 16 17").is_err());
+    }
+
+    #[test]
+    fn test_parse_fetch() {
+        let command = Command::Fetch { year: 2022, day: 9 };
+        assert_eq!(Ok(command), parse_str("fetch --year 2022 9"));
+        assert!(parse_str("fetch").is_err());
+        assert!(parse_str("fetch all").is_err());
+    }
+
+    #[test]
+    fn test_parse_submit() {
+        let command = Command::Submit {
+            year: 2023,
+            day: 6,
+            part: Part::Two,
+        };
+        assert_eq!(Ok(command), parse_str("submit 6 2"));
+        assert!(parse_str("submit 6").is_err());
+        assert!(parse_str("submit 6 all").is_err());
+        assert!(parse_str("submit all 1").is_err());
+    }
+
+    #[test]
+    fn test_parse_generate() {
+        let command = Command::Generate {
+            year: 2023,
+            day: 10,
+            seed: DEFAULT_SEED,
+            scale: DEFAULT_SCALE,
+        };
+        assert_eq!(Ok(command), parse_str("generate 10"));
+        let command = Command::Generate {
+            year: 2023,
+            day: 12,
+            seed: 42,
+            scale: 100,
+        };
+        assert_eq!(Ok(command), parse_str("generate 12 --seed 42 --scale 100"));
+        assert!(parse_str("generate 12 --scale 0").is_err());
+        assert!(parse_str("generate all").is_err());
+    }
+
+    #[test]
+    fn test_parse_animate() {
+        let command = Command::Animate {
+            year: 2023,
+            day: 14,
+            part: Part::Two,
+            input: None,
+            delay: DEFAULT_DELAY,
+            filter: StepFilter::default(),
+        };
+        assert_eq!(Ok(command), parse_str("animate 14 2"));
+        let command = Command::Animate {
+            year: 2023,
+            day: 14,
+            part: Part::Two,
+            input: Some(PathBuf::from("example.txt")),
+            delay: Duration::from_millis(200),
+            filter: StepFilter {
+                kind: Some("cycle".to_string()),
+                every: 10,
+            },
+        };
+        let args = "animate 14 2 --input example.txt --delay 200 --steps cycle --every 10";
+        assert_eq!(Ok(command), parse_str(args));
+        assert!(parse_str("animate 14 2 --every 0").is_err());
+        assert!(parse_str("animate 14 all").is_err());
+        assert!(parse_str("animate 14").is_err());
+    }
+
+    #[test]
+    fn test_parse_invalid_day() {
+        assert!(parse_str("run 26").is_err());
+        assert!(parse_str("run").is_err());
+        assert!(parse_str("run all --input example.txt").is_err());
+    }
+}
@@ -0,0 +1,186 @@
+use aoc_core::{Allocations, Answer, ParseError, Part, Puzzle};
+use std::time::Duration;
+
+pub const DEFAULT_WARMUP: usize = 2;
+pub const DEFAULT_RUNS: usize = 10;
+
+/// Spread of the measured durations
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub median: Duration,
+    pub min: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    pub fn new(mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty(), "stats need at least one sample");
+        samples.sort();
+        let middle = samples.len() / 2;
+        let median = match samples.len() % 2 {
+            0 => (samples[middle - 1] + samples[middle]) / 2,
+            _ => samples[middle],
+        };
+        Stats {
+            median,
+            min: samples[0],
+            max: samples[samples.len() - 1],
+        }
+    }
+}
+
+/// Benchmark of one part of a day
+pub struct Measurement {
+    pub day: u8,
+    pub part: Part,
+    pub result: Result<Timings, String>, // Why the part could not be measured
+}
+
+pub struct Timings {
+    pub answer: Answer,
+    pub parse: Stats,
+    pub solve: Stats,
+    pub allocations: Option<Allocations>, // Of the last run, when they are counted
+}
+
+/// Times a part `runs` times, which should be at least one, after discarding `warmup` runs
+pub fn measure(
+    puzzle: &dyn Puzzle,
+    input: &str,
+    part: Part,
+    warmup: usize,
+    runs: usize,
+) -> Result<Timings, ParseError> {
+    for _ in 0..warmup {
+        puzzle.solve_timed(input, part)?;
+    }
+    let mut answer = Answer::Unsolved;
+    let mut allocations = None;
+    let mut parse: Vec<Duration> = Vec::with_capacity(runs);
+    let mut solve: Vec<Duration> = Vec::with_capacity(runs);
+    for _ in 0..runs {
+        let timed = puzzle.solve_timed(input, part)?;
+        parse.push(timed.parse);
+        solve.push(timed.solve);
+        answer = timed.answer;
+        allocations = timed.allocations;
+    }
+    Ok(Timings {
+        answer,
+        parse: Stats::new(parse),
+        solve: Stats::new(solve),
+        allocations,
+    })
+}
+
+pub fn print_table(year: u16, warmup: usize, runs: usize, measurements: &[Measurement]) {
+    println!(
+        "{} benchmark, {} runs after {} warmup runs",
+        year, runs, warmup
+    );
+    // Allocation columns are only shown when the runner was built to count allocations
+    let counted = measurements.iter().any(
+        |measurement| matches!(&measurement.result, Ok(timings) if timings.allocations.is_some()),
+    );
+    let allocation_header = match counted {
+        true => format!("{:>10}  {:>10}  {:>10}  ", "allocs", "bytes", "peak"),
+        false => String::new(),
+    };
+    println!(
+        "day  part  {:>10}  {:>10}  {:>10}  {:>10}  {:>10}  {:>10}  {}answer",
+        "parse", "min", "max", "solve", "min", "max", allocation_header
+    );
+
+    let mut total = Duration::ZERO;
+    for measurement in measurements {
+        let timings = match &measurement.result {
+            Ok(timings) => timings,
+            Err(reason) => {
+                println!(
+                    " {:02}  {:>4}  {}",
+                    measurement.day,
+                    measurement.part.number(),
+                    reason
+                );
+                continue;
+            }
+        };
+        total += timings.parse.median + timings.solve.median;
+        let allocation_columns = match timings.allocations {
+            Some(allocations) if counted => format!(
+                "{:>10}  {:>10}  {:>10}  ",
+                allocations.count,
+                format_bytes(allocations.bytes),
+                format_bytes(allocations.peak)
+            ),
+            _ => " ".repeat(allocation_header.len()),
+        };
+        println!(
+            " {:02}  {:>4}  {:>10}  {:>10}  {:>10}  {:>10}  {:>10}  {:>10}  {}{}",
+            measurement.day,
+            measurement.part.number(),
+            format_duration(timings.parse.median),
+            format_duration(timings.parse.min),
+            format_duration(timings.parse.max),
+            format_duration(timings.solve.median),
+            format_duration(timings.solve.min),
+            format_duration(timings.solve.max),
+            allocation_columns,
+            timings.answer
+        );
+    }
+    println!("total of medians: {}", format_duration(total));
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+    match nanos {
+        0..=999 => format!("{}ns", nanos),
+        1_000..=999_999 => format!("{:.1}µs", nanos as f64 / 1e3),
+        1_000_000..=999_999_999 => format!("{:.2}ms", nanos as f64 / 1e6),
+        _ => format!("{:.2}s", duration.as_secs_f64()),
+    }
+}
+
+pub fn format_bytes(bytes: u64) -> String {
+    match bytes {
+        0..=1023 => format!("{}B", bytes),
+        1024..=1_048_575 => format!("{:.1}KiB", bytes as f64 / 1024.0),
+        1_048_576..=1_073_741_823 => format!("{:.1}MiB", bytes as f64 / 1_048_576.0),
+        _ => format!("{:.2}GiB", bytes as f64 / 1_073_741_824.0),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let millis = |values: &[u64]| values.iter().map(|&ms| Duration::from_millis(ms)).collect();
+        let stats = Stats::new(millis(&[5, 1, 9]));
+        assert_eq!(Duration::from_millis(5), stats.median);
+        assert_eq!(Duration::from_millis(1), stats.min);
+        assert_eq!(Duration::from_millis(9), stats.max);
+        assert_eq!(
+            Duration::from_millis(4),
+            Stats::new(millis(&[2, 6, 1, 9])).median
+        );
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!("999ns", format_duration(Duration::from_nanos(999)));
+        assert_eq!("12.3µs", format_duration(Duration::from_nanos(12_345)));
+        assert_eq!("4.57ms", format_duration(Duration::from_micros(4_567)));
+        assert_eq!("1.50s", format_duration(Duration::from_millis(1_500)));
+    }
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!("512B", format_bytes(512));
+        assert_eq!("1.5KiB", format_bytes(1536));
+        assert_eq!("3.0MiB", format_bytes(3 * 1024 * 1024));
+        assert_eq!("2.00GiB", format_bytes(2 << 30));
+    }
+}
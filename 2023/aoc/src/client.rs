@@ -0,0 +1,376 @@
+use aoc_core::Part;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Session cookie of a logged in adventofcode.com account
+pub const SESSION_VAR: &str = "AOC_SESSION";
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+/// Directory of the workspace for state kept between runs
+pub const STATE_DIR: &str = ".aoc";
+const LAST_REQUEST_FILE: &str = "last-request";
+
+/// Shortest time between two requests to the site
+pub const MIN_INTERVAL: Duration = Duration::from_secs(5);
+
+// The site asks automated tools to identify themselves
+const USER_AGENT: &str = "github.com/frendsick/advent-of-code runner";
+
+#[derive(Debug)]
+pub enum ClientError {
+    MissingSession,
+    Request(String), // No response was received
+    Status { url: String, status: u16 },
+    Io { path: PathBuf, error: io::Error },
+}
+
+impl fmt::Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ClientError::MissingSession => {
+                write!(
+                    f,
+                    "set {} to the session cookie of your account",
+                    SESSION_VAR
+                )
+            }
+            // Transports already name the URL in their errors
+            ClientError::Request(reason) => write!(f, "request failed: {}", reason),
+            ClientError::Status { url, status } => {
+                write!(f, "{}: HTTP {}", url, status)?;
+                match status {
+                    400 => write!(f, " (the session cookie was rejected)"),
+                    404 => write!(f, " (the puzzle is not unlocked yet)"),
+                    _ => Ok(()),
+                }
+            }
+            ClientError::Io { path, error } => write!(f, "{}: {}", path.display(), error),
+        }
+    }
+}
+
+/// Response to a request, whatever its status
+#[derive(Debug, PartialEq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+/// Sends the requests, so tests can talk to a local server instead of the site
+pub trait Transport {
+    /// Fails only when no response was received
+    fn get(&self, url: &str, session: &str) -> Result<Response, String>;
+    fn post(&self, url: &str, session: &str, form: &[(&str, &str)]) -> Result<Response, String>;
+}
+
+/// Transport over HTTPS to the real site
+pub struct Ureq {
+    agent: ureq::Agent,
+}
+
+impl Ureq {
+    pub fn new() -> Self {
+        let agent = ureq::AgentBuilder::new()
+            .user_agent(USER_AGENT)
+            .timeout(Duration::from_secs(30))
+            .build();
+        Ureq { agent }
+    }
+}
+
+impl Transport for Ureq {
+    fn get(&self, url: &str, session: &str) -> Result<Response, String> {
+        let request = self.agent.get(url);
+        into_response(
+            request
+                .set("Cookie", &format!("session={}", session))
+                .call(),
+        )
+    }
+
+    fn post(&self, url: &str, session: &str, form: &[(&str, &str)]) -> Result<Response, String> {
+        let request = self.agent.post(url);
+        into_response(
+            request
+                .set("Cookie", &format!("session={}", session))
+                .send_form(form),
+        )
+    }
+}
+
+// Error statuses are responses too, only failing to get one is an error
+fn into_response(result: Result<ureq::Response, ureq::Error>) -> Result<Response, String> {
+    let response = match result {
+        Ok(response) => response,
+        Err(ureq::Error::Status(_, response)) => response,
+        Err(err) => return Err(err.to_string()),
+    };
+    let status = response.status();
+    let body = response.into_string().map_err(|err| err.to_string())?;
+    Ok(Response { status, body })
+}
+
+/// Keeps the time of the last request on disk, so separate runs share the interval
+pub struct RateLimiter {
+    path: PathBuf,
+    interval: Duration,
+}
+
+impl RateLimiter {
+    pub fn new(state_dir: &Path, interval: Duration) -> Self {
+        RateLimiter {
+            path: state_dir.join(LAST_REQUEST_FILE),
+            interval,
+        }
+    }
+
+    /// Time left until the next request is allowed
+    pub fn remaining(&self, now: SystemTime) -> Duration {
+        let last = fs::read_to_string(&self.path)
+            .ok()
+            .and_then(|millis| millis.trim().parse().ok())
+            .map(|millis| UNIX_EPOCH + Duration::from_millis(millis));
+        match last {
+            // A clock that went backwards counts from now
+            Some(last) => (last + self.interval)
+                .duration_since(now)
+                .unwrap_or_default()
+                .min(self.interval),
+            None => Duration::ZERO,
+        }
+    }
+
+    /// Sleeps until a request is allowed and records it as the last one
+    pub fn wait(&self) -> Result<(), ClientError> {
+        std::thread::sleep(self.remaining(SystemTime::now()));
+        let millis = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis();
+        let io_error = |error| ClientError::Io {
+            path: self.path.clone(),
+            error,
+        };
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir).map_err(io_error)?;
+        }
+        fs::write(&self.path, millis.to_string()).map_err(io_error)
+    }
+}
+
+/// Rate limited access to the puzzles of a logged in account
+pub struct Client<T: Transport> {
+    transport: T,
+    base_url: String,
+    session: Option<String>, // Only needed once something is requested
+    limiter: RateLimiter,
+}
+
+impl Client<Ureq> {
+    pub fn from_env(workspace: &Path) -> Self {
+        let session = std::env::var(SESSION_VAR)
+            .ok()
+            .map(|session| session.trim().to_string())
+            .filter(|session| !session.is_empty());
+        Client {
+            transport: Ureq::new(),
+            base_url: BASE_URL.to_string(),
+            session,
+            limiter: RateLimiter::new(&workspace.join(STATE_DIR), MIN_INTERVAL),
+        }
+    }
+}
+
+impl<T: Transport> Client<T> {
+    fn get(&self, path: &str) -> Result<String, ClientError> {
+        self.send(path, None)
+    }
+
+    fn post(&self, path: &str, form: &[(&str, &str)]) -> Result<String, ClientError> {
+        self.send(path, Some(form))
+    }
+
+    fn send(&self, path: &str, form: Option<&[(&str, &str)]>) -> Result<String, ClientError> {
+        let session = self.session.as_ref().ok_or(ClientError::MissingSession)?;
+        let url = format!("{}{}", self.base_url, path);
+        self.limiter.wait()?;
+        let response = match form {
+            Some(form) => self.transport.post(&url, session, form),
+            None => self.transport.get(&url, session),
+        };
+        let response = response.map_err(ClientError::Request)?;
+        match response.status {
+            200 => Ok(response.body),
+            status => Err(ClientError::Status { url, status }),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Fetched {
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+/// Downloads the input of the day into the day crate, unless it is there already
+pub fn fetch_input<T: Transport>(
+    client: &Client<T>,
+    path: &Path,
+    year: u16,
+    day: u8,
+) -> Result<Fetched, ClientError> {
+    // Inputs never change, so a cached one is never requested again
+    if path.is_file() {
+        return Ok(Fetched::Cached(path.to_path_buf()));
+    }
+    let input = client.get(&format!("/{}/day/{}/input", year, day))?;
+    let io_error = |error| ClientError::Io {
+        path: path.to_path_buf(),
+        error,
+    };
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(io_error)?;
+    }
+    fs::write(path, input).map_err(io_error)?;
+    Ok(Fetched::Downloaded(path.to_path_buf()))
+}
+
+/// Posts the answer of the part and returns the page with the verdict
+pub fn submit_answer<T: Transport>(
+    client: &Client<T>,
+    year: u16,
+    day: u8,
+    part: Part,
+    answer: &str,
+) -> Result<String, ClientError> {
+    let level = part.number().to_string();
+    let form = [("level", level.as_str()), ("answer", answer)];
+    client.post(&format!("/{}/day/{}/answer", year, day), &form)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::temp::TempDir;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::sync::mpsc;
+
+    fn temp_dir(name: &str) -> TempDir {
+        TempDir::new(&format!("client-{}", name))
+    }
+
+    // Answers one request and sends back the request it received
+    fn stub_server(status: u16, body: &'static str) -> (String, mpsc::Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let (sender, receiver) = mpsc::channel();
+        std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut request = String::new();
+            // Headers end with an empty line
+            while reader.read_line(&mut request).unwrap() > 2 {}
+            let length = request
+                .lines()
+                .find_map(|line| line.strip_prefix("Content-Length: "))
+                .map_or(0, |length| length.parse().unwrap());
+            let mut content = vec![0; length];
+            reader.read_exact(&mut content).unwrap();
+            request.push_str(&String::from_utf8(content).unwrap());
+            write!(
+                stream,
+                "HTTP/1.1 {} Stub\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            )
+            .unwrap();
+            sender.send(request).unwrap();
+        });
+        (base_url, receiver)
+    }
+
+    fn client(base_url: String, dir: &Path) -> Client<Ureq> {
+        Client {
+            transport: Ureq::new(),
+            base_url,
+            session: Some("secret".to_string()),
+            limiter: RateLimiter::new(&dir.join(STATE_DIR), Duration::ZERO),
+        }
+    }
+
+    #[test]
+    fn test_fetch_input() {
+        let dir = temp_dir("fetch");
+        let path = dir.join("day05/input.txt");
+        let (base_url, requests) = stub_server(200, "seeds: 79 14\n");
+        let client = client(base_url, &dir);
+        assert_eq!(
+            Fetched::Downloaded(path.clone()),
+            fetch_input(&client, &path, 2023, 5).unwrap()
+        );
+        assert_eq!("seeds: 79 14\n", fs::read_to_string(&path).unwrap());
+        let request = requests.recv().unwrap();
+        assert!(request.starts_with("GET /2023/day/5/input HTTP/1.1\r\n"));
+        assert!(request.contains("\r\nCookie: session=secret\r\n"));
+
+        // The stub has stopped, so only the cache can answer
+        assert_eq!(
+            Fetched::Cached(path.clone()),
+            fetch_input(&client, &path, 2023, 5).unwrap()
+        );
+        assert!(dir.join(STATE_DIR).join(LAST_REQUEST_FILE).is_file());
+    }
+
+    #[test]
+    fn test_fetch_input_errors() {
+        let dir = temp_dir("errors");
+        let path = dir.join("day25/input.txt");
+        let (base_url, _requests) = stub_server(404, "Not Found");
+        let mut client = client(base_url, &dir);
+        let error = fetch_input(&client, &path, 2023, 25).unwrap_err();
+        assert!(matches!(error, ClientError::Status { status: 404, .. }));
+        assert!(!path.exists());
+
+        client.session = None;
+        let error = fetch_input(&client, &path, 2023, 25).unwrap_err();
+        assert!(matches!(error, ClientError::MissingSession));
+    }
+
+    #[test]
+    fn test_submit_answer() {
+        let dir = temp_dir("submit");
+        let page = "<article><p>That's the right answer!</p></article>";
+        let (base_url, requests) = stub_server(200, page);
+        let client = client(base_url, &dir);
+        assert_eq!(
+            page,
+            submit_answer(&client, 2023, 6, Part::Two, "71503").unwrap()
+        );
+        let request = requests.recv().unwrap();
+        assert!(request.starts_with("POST /2023/day/6/answer HTTP/1.1\r\n"));
+        assert!(request.ends_with("\r\n\r\nlevel=2&answer=71503"));
+    }
+
+    #[test]
+    fn test_rate_limiter() {
+        let dir = temp_dir("limiter");
+        let interval = Duration::from_secs(60);
+        let limiter = RateLimiter::new(&dir, interval);
+        let now = SystemTime::now();
+        assert_eq!(Duration::ZERO, limiter.remaining(now));
+
+        let last = UNIX_EPOCH + Duration::from_millis(1_700_000_000_000);
+        fs::write(dir.join(LAST_REQUEST_FILE), "1700000000000").unwrap();
+        assert_eq!(interval, limiter.remaining(last));
+        assert_eq!(
+            Duration::from_secs(15),
+            limiter.remaining(last + Duration::from_secs(45))
+        );
+        assert_eq!(Duration::ZERO, limiter.remaining(last + interval));
+    }
+}
@@ -0,0 +1,65 @@
+use aoc_core::Puzzle;
+
+// Every solved day, in the order they are run
+pub static PUZZLES: &[&dyn Puzzle] = &[
+    &day01::Day01,
+    &day02::Day02,
+    &day03::Day03,
+    &day04::Day04,
+    &day05::Day05,
+    &day06::Day06,
+    &day07::Day07,
+    &day08::Day08,
+    &day09::Day09,
+    &day10::Day10,
+    &day11::Day11,
+    &day12::Day12,
+    &day13::Day13,
+    &day14::Day14,
+    &day15::Day15,
+];
+
+pub fn select(year: u16, day: Option<u8>) -> Vec<&'static dyn Puzzle> {
+    PUZZLES
+        .iter()
+        .copied()
+        .filter(|puzzle| puzzle.year() == year && day.is_none_or(|day| puzzle.day() == day))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::{Answer, Part};
+
+    #[test]
+    fn test_generated_inputs() {
+        for puzzle in PUZZLES {
+            // Days scaffolded by `aoc new` have no generator until one is written
+            let Some(generated) = puzzle.generate(2023, 1) else {
+                continue;
+            };
+            assert_eq!(generated, puzzle.generate(2023, 1).unwrap());
+            for part in Part::ALL {
+                // Every first part is quick to solve, which checks that the input parses
+                let expected = generated.answer(part);
+                if expected.is_none() && part == Part::Two {
+                    continue;
+                }
+                let answer = puzzle
+                    .solve(&generated.input, part)
+                    .unwrap_or_else(|err| panic!("{}", err));
+                if let Some(expected) = expected.filter(|_| answer != Answer::Unsolved) {
+                    let day = puzzle.day();
+                    assert_eq!(
+                        expected.to_string(),
+                        answer.to_string(),
+                        "day {:02} part {}",
+                        day,
+                        part
+                    );
+                }
+            }
+        }
+    }
+}
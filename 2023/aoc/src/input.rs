@@ -0,0 +1,232 @@
+use std::ffi::OsString;
+use std::fmt;
+use std::io::{self, IsTerminal, Read};
+use std::path::{Path, PathBuf};
+
+/// Directory holding puzzle inputs named `dayNN.txt`
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Workspace holding the day crates, for runners installed outside of it
+pub const WORKSPACE_VAR: &str = "AOC_WORKSPACE";
+
+#[derive(Debug)]
+pub enum InputError {
+    Unreadable {
+        path: PathBuf,
+        error: io::Error,
+    },
+    Missing {
+        day: u8,
+        stdin: bool, // Whether stdin was read, which only happens for a single day
+        tried: Vec<PathBuf>,
+    },
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InputError::Unreadable { path, error } => {
+                write!(f, "cannot read input {}: {}", path.display(), error)
+            }
+            InputError::Missing { day, stdin, tried } => {
+                let mut sources: Vec<String> = Vec::new();
+                if *stdin {
+                    sources.push("stdin".to_string());
+                }
+                sources.extend(tried.iter().map(|path| path.display().to_string()));
+                write!(
+                    f,
+                    "no input found for day {:02}, tried {}",
+                    day,
+                    sources.join(", ")
+                )?;
+                match stdin {
+                    true => write!(f, " (pass --input <path> or pipe the input to stdin)"),
+                    false => write!(f, " (save the input to one of those files)"),
+                }
+            }
+        }
+    }
+}
+
+/// Resolves puzzle inputs from, in order: an explicit path, stdin, `AOC_INPUT_DIR`, and the
+/// `input.txt` file of the day crate
+pub struct InputResolver {
+    path: Option<PathBuf>,
+    stdin: Option<String>,
+    stdin_read: bool, // Even when it was empty
+    input_dir: Option<PathBuf>,
+    workspace: PathBuf,
+}
+
+impl InputResolver {
+    /// Stdin can only feed a single day, and is not read at all when a path is given
+    pub fn from_env(path: Option<PathBuf>, single_day: bool) -> Result<Self, InputError> {
+        let stdin_read = path.is_none() && single_day && !io::stdin().is_terminal();
+        let stdin = match stdin_read {
+            true => read_stdin()?,
+            false => None,
+        };
+        Ok(InputResolver {
+            path,
+            stdin,
+            stdin_read,
+            input_dir: std::env::var_os(INPUT_DIR_VAR).map(PathBuf::from),
+            workspace: workspace_dir(),
+        })
+    }
+
+    /// Whether the input was given by path or stdin instead of found for the day, in which
+    /// case it may not be the puzzle input that the confirmed answers belong to
+    pub fn is_given(&self) -> bool {
+        self.path.is_some() || self.stdin.is_some()
+    }
+
+    pub fn resolve(&self, day: u8) -> Result<String, InputError> {
+        if let Some(path) = &self.path {
+            return read_file(path);
+        }
+        if let Some(stdin) = &self.stdin {
+            return Ok(stdin.clone());
+        }
+
+        let mut tried: Vec<PathBuf> = Vec::new();
+        if let Some(input_dir) = &self.input_dir {
+            tried.push(input_dir.join(format!("day{:02}.txt", day)));
+        }
+        tried.push(default_input_path(&self.workspace, day));
+
+        match tried.iter().find(|path| path.is_file()) {
+            Some(path) => read_file(path),
+            None => Err(InputError::Missing {
+                day,
+                stdin: self.stdin_read,
+                tried,
+            }),
+        }
+    }
+}
+
+/// Conventional location of the puzzle input inside the day crate
+pub fn default_input_path(workspace: &Path, day: u8) -> PathBuf {
+    workspace.join(format!("day{:02}", day)).join("input.txt")
+}
+
+/// Workspace from `AOC_WORKSPACE`, else the one the current directory is in, else the one
+/// the runner was built in
+pub fn workspace_dir() -> PathBuf {
+    let current_dir = std::env::current_dir().ok();
+    find_workspace(std::env::var_os(WORKSPACE_VAR), current_dir.as_deref()).unwrap_or_else(|| {
+        // The day crates live next to the runner in the workspace
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .parent()
+            .expect("runner crate is inside the workspace")
+            .to_path_buf()
+    })
+}
+
+fn find_workspace(var: Option<OsString>, current_dir: Option<&Path>) -> Option<PathBuf> {
+    if let Some(workspace) = var.filter(|workspace| !workspace.is_empty()) {
+        return Some(PathBuf::from(workspace));
+    }
+    // A workspace is recognized by the runner crate inside it
+    current_dir?
+        .ancestors()
+        .find(|dir| dir.join("aoc").join("Cargo.toml").is_file())
+        .map(Path::to_path_buf)
+}
+
+fn read_stdin() -> Result<Option<String>, InputError> {
+    let mut input = String::new();
+    io::stdin()
+        .read_to_string(&mut input)
+        .map_err(|error| InputError::Unreadable {
+            path: PathBuf::from("<stdin>"),
+            error,
+        })?;
+
+    // Empty stdin, like `/dev/null` in scripts, falls through to the other sources
+    Ok(Some(input).filter(|input| !input.is_empty()))
+}
+
+fn read_file(path: &Path) -> Result<String, InputError> {
+    std::fs::read_to_string(path).map_err(|error| InputError::Unreadable {
+        path: path.to_path_buf(),
+        error,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::temp::TempDir;
+    use std::fs;
+
+    fn temp_workspace(name: &str) -> TempDir {
+        let workspace = TempDir::new(&format!("input-{}", name));
+        fs::create_dir_all(workspace.join("day05")).unwrap();
+        fs::create_dir_all(workspace.join("inputs")).unwrap();
+        workspace
+    }
+
+    fn resolver(workspace: &Path) -> InputResolver {
+        InputResolver {
+            path: None,
+            stdin: None,
+            stdin_read: false,
+            input_dir: Some(workspace.join("inputs")),
+            workspace: workspace.to_path_buf(),
+        }
+    }
+
+    #[test]
+    fn test_resolve_order() {
+        let workspace = temp_workspace("order");
+        fs::write(workspace.join("day05/input.txt"), "default").unwrap();
+        let mut resolver = resolver(&workspace);
+        assert_eq!("default", resolver.resolve(5).unwrap());
+
+        fs::write(workspace.join("inputs/day05.txt"), "env").unwrap();
+        assert_eq!("env", resolver.resolve(5).unwrap());
+
+        resolver.stdin = Some("stdin".to_string());
+        assert_eq!("stdin", resolver.resolve(5).unwrap());
+
+        fs::write(workspace.join("explicit.txt"), "explicit").unwrap();
+        resolver.path = Some(workspace.join("explicit.txt"));
+        assert_eq!("explicit", resolver.resolve(5).unwrap());
+    }
+
+    #[test]
+    fn test_find_workspace() {
+        let workspace = temp_workspace("find");
+        fs::create_dir_all(workspace.join("aoc")).unwrap();
+        fs::write(workspace.join("aoc/Cargo.toml"), "").unwrap();
+        let day_dir = workspace.join("day05");
+        assert_eq!(
+            Some(workspace.to_path_buf()),
+            find_workspace(None, Some(&day_dir))
+        );
+        let var = Some(OsString::from("/elsewhere"));
+        assert_eq!(
+            Some(PathBuf::from("/elsewhere")),
+            find_workspace(var, Some(&day_dir))
+        );
+        assert_eq!(None, find_workspace(None, Some(&std::env::temp_dir())));
+    }
+
+    #[test]
+    fn test_resolve_missing() {
+        let workspace = temp_workspace("missing");
+        let mut resolver = resolver(&workspace);
+        let error = resolver.resolve(6).unwrap_err();
+        assert!(matches!(error, InputError::Missing { day: 6, ref tried, .. } if tried.len() == 2));
+        assert!(error.to_string().contains("day06/input.txt"));
+        assert!(!error.to_string().contains("stdin"));
+
+        // Empty stdin is tried before the files
+        resolver.stdin_read = true;
+        let message = resolver.resolve(6).unwrap_err().to_string();
+        assert!(message.contains("tried stdin, "));
+    }
+}
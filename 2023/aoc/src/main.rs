@@ -0,0 +1,553 @@
+mod answers;
+mod args;
+mod bench;
+mod client;
+mod days;
+mod input;
+mod pool;
+mod records;
+mod report;
+mod scaffold;
+mod submit;
+
+use answers::{Answers, Verification};
+use aoc_core::trace::{self, Entry};
+use aoc_core::{Animation, Answer, ParseError, Part, Puzzle, StepFilter, Timed};
+use args::{Command, Render, Trace};
+use input::{InputError, InputResolver};
+use pool::Outcome;
+use report::{Failure, Record, Report, Solved};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::Duration;
+use submit::{Guesses, Verdict};
+
+#[cfg(feature = "count-allocations")]
+#[global_allocator]
+static ALLOCATOR: aoc_core::memory::CountingAllocator = aoc_core::memory::CountingAllocator;
+
+/// Pixels per tile in rendered pictures
+const CELL_SIZE: usize = 4;
+
+/// Answer of a part and the events captured while solving it
+type Solving = (Result<Timed, ParseError>, Vec<Entry>);
+
+/// Optional parts of `aoc run`
+struct RunOptions {
+    render: Option<Render>,
+    report: Option<Report>,
+    jobs: Option<usize>,
+    timeout: Option<Duration>,
+    trace: Option<Trace>,
+}
+
+fn main() -> ExitCode {
+    let result = args::parse(std::env::args().skip(1)).and_then(execute);
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("error: {}", message);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn execute(command: Command) -> Result<(), String> {
+    match command {
+        Command::Run {
+            year,
+            day,
+            parts,
+            input,
+            render,
+            report,
+            jobs,
+            timeout,
+            trace,
+        } => {
+            let options = RunOptions {
+                render,
+                report,
+                jobs,
+                timeout,
+                trace,
+            };
+            run(year, day, &parts, input, options)
+        }
+        Command::Bench {
+            year,
+            day,
+            parts,
+            input,
+            warmup,
+            runs,
+        } => bench(year, day, &parts, input, warmup, runs),
+        Command::Verify { year, day, record } => verify(year, day, record),
+        Command::New { day } => {
+            let day_dir =
+                scaffold::new_day(&input::workspace_dir(), day).map_err(|err| err.to_string())?;
+            println!("created {}", day_dir.display());
+            Ok(())
+        }
+        Command::Fetch { year, day } => fetch(year, day),
+        Command::Submit { year, day, part } => submit(year, day, part),
+        Command::Generate {
+            year,
+            day,
+            seed,
+            scale,
+        } => generate(year, day, seed, scale),
+        Command::Animate {
+            year,
+            day,
+            part,
+            input,
+            delay,
+            filter,
+        } => animate(year, day, part, input, delay, filter),
+        Command::Help => {
+            println!("{}", args::USAGE);
+            Ok(())
+        }
+    }
+}
+
+fn select(year: u16, day: Option<u8>) -> Result<Vec<&'static dyn Puzzle>, String> {
+    let puzzles = days::select(year, day);
+    if puzzles.is_empty() {
+        return Err(match day {
+            Some(day) => format!("no solution registered for {} day {:02}", year, day),
+            None => format!("no solutions registered for {}", year),
+        });
+    }
+    Ok(puzzles)
+}
+
+fn run(
+    year: u16,
+    day: Option<u8>,
+    parts: &[Part],
+    input: Option<PathBuf>,
+    options: RunOptions,
+) -> Result<(), String> {
+    let puzzles = select(year, day)?;
+    let inputs = InputResolver::from_env(input, day.is_some()).map_err(|err| err.to_string())?;
+    // Answers are only verified for the report, and only against the day's own input
+    let answers = match options.report {
+        Some(_) if !inputs.is_given() => {
+            Answers::load(&answers::answers_path(&input::workspace_dir()))?
+        }
+        _ => Answers::default(),
+    };
+    // Days without an input are reported with the results instead of stopping the run
+    let days: Vec<(&'static dyn Puzzle, Result<String, InputError>)> = puzzles
+        .into_iter()
+        .map(|puzzle| (puzzle, inputs.resolve(puzzle.day())))
+        .collect();
+
+    // Every part is a job of its own, so a runaway part only holds up itself. Events are
+    // captured on the thread that solves the part.
+    let mut jobs: Vec<pool::Job<Solving>> = Vec::new();
+    for (puzzle, input) in &days {
+        let Ok(input) = input else {
+            continue;
+        };
+        let level = match &options.trace {
+            Some(trace) if trace.traces(puzzle.day()) => Some(trace.level),
+            _ => None,
+        };
+        for &part in parts {
+            let (puzzle, input) = (*puzzle, input.clone());
+            jobs.push(Box::new(move || {
+                let solve = || puzzle.solve_timed(&input, part);
+                match level {
+                    Some(level) => trace::capture(level, solve),
+                    None => (solve(), Vec::new()),
+                }
+            }));
+        }
+    }
+    let workers = options
+        .jobs
+        .unwrap_or_else(|| std::thread::available_parallelism().map_or(1, |workers| workers.get()));
+    let mut outcomes = pool::run(jobs, workers, options.timeout).into_iter();
+
+    let mut records: Vec<Record> = Vec::new();
+    let mut events = String::new();
+    let (mut missing, mut failures, mut timeouts, mut panics) = (0, 0, 0, 0);
+    let mut render_failures = 0;
+    for (puzzle, input) in days {
+        let input = match input {
+            Ok(input) => input,
+            Err(err) => {
+                eprintln!("error: {}", err);
+                missing += 1;
+                let error = match err {
+                    InputError::Missing { .. } => "missing input".to_string(),
+                    err => err.to_string(),
+                };
+                records.extend(parts.iter().map(|&part| Record {
+                    year: puzzle.year(),
+                    day: puzzle.day(),
+                    part,
+                    outcome: Err(Failure::Error(error.clone())),
+                }));
+                continue;
+            }
+        };
+        let mut parse_failed = false;
+        for &part in parts {
+            let outcome = outcomes.next().expect("every part should have an outcome");
+            let label = format!("{} day {:02} part {}", puzzle.year(), puzzle.day(), part);
+            let record = |outcome| Record {
+                year: puzzle.year(),
+                day: puzzle.day(),
+                part,
+                outcome,
+            };
+            let outcome = match outcome {
+                Outcome::Done((result, entries)) => {
+                    for entry in entries {
+                        events.push_str(&format!("{}: {}\n", label, entry));
+                    }
+                    Outcome::Done(result)
+                }
+                Outcome::TimedOut => Outcome::TimedOut,
+                Outcome::Panicked => Outcome::Panicked,
+            };
+            let timed = match outcome {
+                Outcome::Done(Ok(timed)) => timed,
+                Outcome::Done(Err(err)) => {
+                    // A malformed input fails every part, so report it once
+                    if !parse_failed {
+                        eprintln!("error: {}", err);
+                        failures += 1;
+                        parse_failed = true;
+                    }
+                    let error =
+                        format!("line {}, column {}: {}", err.line, err.column, err.message);
+                    records.push(record(Err(Failure::Error(error))));
+                    continue;
+                }
+                Outcome::TimedOut => {
+                    let failure = Failure::TimedOut(options.timeout.unwrap_or_default());
+                    println!("{}: {}", label, failure);
+                    timeouts += 1;
+                    records.push(record(Err(failure)));
+                    continue;
+                }
+                Outcome::Panicked => {
+                    println!("{}: panicked", label);
+                    panics += 1;
+                    records.push(record(Err(Failure::Error("panicked".to_string()))));
+                    continue;
+                }
+            };
+            println!("{}: {}", label, timed.answer);
+            if let Some(render) = &options.render {
+                match draw(puzzle, &input, part, render) {
+                    Ok(Some(path)) => eprintln!("wrote {}", path.display()),
+                    Ok(None) if part == parts[0] => eprintln!(
+                        "{} day {:02} has no picture to render",
+                        puzzle.year(),
+                        puzzle.day()
+                    ),
+                    Ok(None) => {}
+                    // The part is solved, but without its picture the run did not do its job
+                    Err(err) => {
+                        eprintln!("error: {}", err);
+                        render_failures += 1;
+                        records.push(record(Err(Failure::Error(err))));
+                        continue;
+                    }
+                }
+            }
+            let expected = answers.expected(puzzle.year(), puzzle.day(), part);
+            let verification = Verification::check(expected, &timed.answer);
+            records.push(record(Ok(Solved {
+                answer: timed.answer,
+                parse: timed.parse,
+                solve: timed.solve,
+                allocations: timed.allocations,
+                verification,
+            })));
+        }
+    }
+
+    if let Some(trace) = &options.trace {
+        write_trace(&trace.path, &events)?;
+        eprintln!("wrote {}", trace.path.display());
+    }
+    if let Some(report) = &options.report {
+        report.write(&records)?;
+        eprintln!("wrote {}", report.path.display());
+    }
+    let mut problems: Vec<String> = Vec::new();
+    match missing {
+        0 => {}
+        1 => problems.push("1 input missing".to_string()),
+        n => problems.push(format!("{} inputs missing", n)),
+    }
+    match failures {
+        0 => {}
+        1 => problems.push("1 input failed to parse".to_string()),
+        n => problems.push(format!("{} inputs failed to parse", n)),
+    }
+    for (count, what) in [(timeouts, "timed out"), (panics, "panicked")] {
+        match count {
+            0 => {}
+            1 => problems.push(format!("1 part {}", what)),
+            n => problems.push(format!("{} parts {}", n, what)),
+        }
+    }
+    match render_failures {
+        0 => {}
+        1 => problems.push("1 picture failed to render".to_string()),
+        n => problems.push(format!("{} pictures failed to render", n)),
+    }
+    match problems.is_empty() {
+        true => Ok(()),
+        false => Err(problems.join(", ")),
+    }
+}
+
+fn write_trace(path: &Path, events: &str) -> Result<(), String> {
+    if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+        std::fs::create_dir_all(dir)
+            .map_err(|err| format!("cannot create {}: {}", dir.display(), err))?;
+    }
+    std::fs::write(path, events).map_err(|err| format!("cannot write {}: {}", path.display(), err))
+}
+
+// Writes the picture of the part as <dir>/dayNN-partN.<format>
+fn draw(
+    puzzle: &dyn Puzzle,
+    input: &str,
+    part: Part,
+    render: &Render,
+) -> Result<Option<PathBuf>, String> {
+    let Some(picture) = puzzle.render(input, part).map_err(|err| err.to_string())? else {
+        return Ok(None);
+    };
+    let file_name = format!(
+        "day{:02}-part{}.{}",
+        puzzle.day(),
+        part,
+        render.format.extension()
+    );
+    let path = render.dir.join(file_name);
+    std::fs::create_dir_all(&render.dir)
+        .map_err(|err| format!("cannot create {}: {}", render.dir.display(), err))?;
+    std::fs::write(&path, picture.encode(render.format, CELL_SIZE))
+        .map_err(|err| format!("cannot write {}: {}", path.display(), err))?;
+    Ok(Some(path))
+}
+
+fn bench(
+    year: u16,
+    day: Option<u8>,
+    parts: &[Part],
+    input: Option<PathBuf>,
+    warmup: usize,
+    runs: usize,
+) -> Result<(), String> {
+    let puzzles = select(year, day)?;
+    let inputs = InputResolver::from_env(input, day.is_some()).map_err(|err| err.to_string())?;
+    let mut measurements: Vec<bench::Measurement> = Vec::new();
+    for puzzle in puzzles {
+        // Days without an input are listed in the table instead of stopping the benchmark
+        let input = inputs.resolve(puzzle.day());
+        for &part in parts {
+            let result = match &input {
+                // The table has room for the location and message, not the snippet
+                Ok(input) => bench::measure(puzzle, input, part, warmup, runs).map_err(|err| {
+                    format!("line {}, column {}: {}", err.line, err.column, err.message)
+                }),
+                Err(InputError::Missing { .. }) => Err("missing input".to_string()),
+                Err(err) => Err(err.to_string()),
+            };
+            measurements.push(bench::Measurement {
+                day: puzzle.day(),
+                part,
+                result,
+            });
+        }
+    }
+    bench::print_table(year, warmup, runs, &measurements);
+    Ok(())
+}
+
+fn fetch(year: u16, day: u8) -> Result<(), String> {
+    let workspace = input::workspace_dir();
+    let client = client::Client::from_env(&workspace);
+    let path = input::default_input_path(&workspace, day);
+    match client::fetch_input(&client, &path, year, day).map_err(|err| err.to_string())? {
+        client::Fetched::Cached(path) => println!("{} is already downloaded", path.display()),
+        client::Fetched::Downloaded(path) => println!("downloaded {}", path.display()),
+    }
+    Ok(())
+}
+
+fn submit(year: u16, day: u8, part: Part) -> Result<(), String> {
+    let puzzle = select(year, Some(day))?[0];
+    let workspace = input::workspace_dir();
+    let answers_path = answers::answers_path(&workspace);
+    let mut answers = Answers::load(&answers_path)?;
+
+    // Only the server's verdict counts, `aoc verify --record` writes unconfirmed answers too
+    let guesses_path = submit::guesses_path(&workspace.join(client::STATE_DIR));
+    let mut guesses = Guesses::load(&guesses_path)?;
+    if let Some(solved) = guesses.solved(year, day, part) {
+        return Err(format!(
+            "day {:02} part {} is already solved as {}",
+            day, part, solved
+        ));
+    }
+
+    let inputs = InputResolver::from_env(None, true).map_err(|err| err.to_string())?;
+    let input = inputs.resolve(day).map_err(|err| err.to_string())?;
+    let answer = puzzle.solve(&input, part).map_err(|err| err.to_string())?;
+    if answer == Answer::Unsolved {
+        return Err(format!("day {:02} part {} is not solved yet", day, part));
+    }
+    let text = answer.to_string();
+
+    // Answers ruled out by earlier verdicts would only cost a timeout
+    guesses
+        .check(year, day, part, &text)
+        .map_err(|refusal| format!("not submitting {}: {}", text, refusal))?;
+    let client = client::Client::from_env(&workspace);
+    let page =
+        client::submit_answer(&client, year, day, part, &text).map_err(|err| err.to_string())?;
+    let verdict = Verdict::parse(&page).ok_or("the response to the submission has no verdict")?;
+    guesses.record(year, day, part, &text, verdict);
+    guesses.save(&guesses_path)?;
+
+    if verdict != Verdict::Correct {
+        return Err(format!("{} was {}", text, verdict));
+    }
+    answers.insert(year, day, part, &answer);
+    answers.save(&answers_path)?;
+    println!(
+        "{} day {:02} part {}: {} is correct, recorded in {}",
+        year,
+        day,
+        part,
+        text,
+        answers_path.display()
+    );
+    Ok(())
+}
+
+fn generate(year: u16, day: u8, seed: u64, scale: usize) -> Result<(), String> {
+    let puzzle = select(year, Some(day))?[0];
+    let generated = puzzle
+        .generate(seed, scale)
+        .ok_or_else(|| format!("day {:02} has no input generator", day))?;
+    print!("{}", generated.input);
+
+    // Known answers go to stderr, so the input can be piped straight to `aoc run`
+    for part in Part::ALL {
+        if let Some(answer) = generated.answer(part) {
+            eprintln!("{} day {:02} part {}: {}", year, day, part, answer);
+        }
+    }
+    Ok(())
+}
+
+fn animate(
+    year: u16,
+    day: u8,
+    part: Part,
+    input: Option<PathBuf>,
+    delay: Duration,
+    filter: StepFilter,
+) -> Result<(), String> {
+    let puzzle = select(year, Some(day))?[0];
+    let input = InputResolver::from_env(input, true)
+        .and_then(|inputs| inputs.resolve(day))
+        .map_err(|err| err.to_string())?;
+
+    let mut stdout = std::io::stdout().lock();
+    let mut animation = Animation::new(&mut stdout, delay, filter);
+    let animated = puzzle
+        .animate(&input, part, &mut animation)
+        .map_err(|err| err.to_string())?;
+    let frames = animation
+        .finish()
+        .map_err(|err| format!("cannot draw the animation: {}", err))?;
+    drop(stdout);
+    if !animated {
+        return Err(format!("{} day {:02} has no animation", year, day));
+    }
+    if frames == 0 {
+        eprintln!("no steps matched the filter");
+    }
+
+    let answer = puzzle.solve(&input, part).map_err(|err| err.to_string())?;
+    println!("{} day {:02} part {}: {}", year, day, part, answer);
+    Ok(())
+}
+
+fn verify(year: u16, day: Option<u8>, record: bool) -> Result<(), String> {
+    let puzzles = select(year, day)?;
+    let answers_path = answers::answers_path(&input::workspace_dir());
+    let mut answers = Answers::load(&answers_path)?;
+    let mut recorded = 0;
+    let inputs = InputResolver::from_env(None, false).map_err(|err| err.to_string())?;
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+    for puzzle in puzzles {
+        let input = inputs.resolve(puzzle.day());
+        for part in Part::ALL {
+            let expected = answers.expected(year, puzzle.day(), part);
+            let status = match (&input, expected) {
+                (Err(_), _) => {
+                    missing += 1;
+                    "missing input".to_string()
+                }
+                (Ok(input), _) => match puzzle.solve(input, part) {
+                    Err(err) => {
+                        failed += 1;
+                        format!(
+                            "fail, line {}, column {}: {}",
+                            err.line, err.column, err.message
+                        )
+                    }
+                    Ok(answer) => match Verification::check(expected, &answer) {
+                        Verification::Pass => {
+                            passed += 1;
+                            format!("pass ({})", answer)
+                        }
+                        Verification::Fail { expected } => {
+                            failed += 1;
+                            format!("fail, expected {} but got {}", expected, answer)
+                        }
+                        Verification::Missing if record && answer != Answer::Unsolved => {
+                            answers.insert(year, puzzle.day(), part, &answer);
+                            recorded += 1;
+                            format!("recorded {}", answer)
+                        }
+                        Verification::Missing => {
+                            missing += 1;
+                            format!("missing answer, got {}", answer)
+                        }
+                    },
+                },
+            };
+            println!("{} day {:02} part {}: {}", year, puzzle.day(), part, status);
+        }
+    }
+
+    if recorded > 0 {
+        answers.save(&answers_path)?;
+    }
+    println!(
+        "{} passed, {} failed, {} missing, {} recorded",
+        passed, failed, missing, recorded
+    );
+    match failed {
+        0 => Ok(()),
+        1 => Err("1 part failed verification".to_string()),
+        n => Err(format!("{} parts failed verification", n)),
+    }
+}
@@ -0,0 +1,123 @@
+use std::collections::VecDeque;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::Mutex;
+use std::thread;
+use std::time::Duration;
+
+/// Work that can be moved to another thread
+pub type Job<T> = Box<dyn FnOnce() -> T + Send>;
+
+#[derive(Debug, PartialEq)]
+pub enum Outcome<T> {
+    Done(T),
+    TimedOut,
+    Panicked,
+}
+
+/// Runs the jobs on at most `workers` threads and returns their outcomes in the order of the
+/// jobs. Every job runs on a thread of its own that its worker watches, so that the worker
+/// can give up on it after `timeout`. Threads cannot be stopped, so a job that timed out
+/// keeps running in the background until the process exits, but it no longer holds up a
+/// worker.
+pub fn run<T: Send + 'static>(
+    jobs: Vec<Job<T>>,
+    workers: usize,
+    timeout: Option<Duration>,
+) -> Vec<Outcome<T>> {
+    let count = jobs.len();
+    let queue: Mutex<VecDeque<(usize, Job<T>)>> =
+        Mutex::new(jobs.into_iter().enumerate().collect());
+    let (sender, receiver) = mpsc::channel();
+    thread::scope(|scope| {
+        for _ in 0..workers.clamp(1, count.max(1)) {
+            let (queue, sender) = (&queue, sender.clone());
+            scope.spawn(move || loop {
+                // The lock is released before the job runs
+                let next = queue.lock().unwrap().pop_front();
+                let Some((index, job)) = next else {
+                    break;
+                };
+                let _ = sender.send((index, watch(job, timeout)));
+            });
+        }
+    });
+    drop(sender);
+
+    let mut outcomes: Vec<Option<Outcome<T>>> = (0..count).map(|_| None).collect();
+    for (index, outcome) in receiver {
+        outcomes[index] = Some(outcome);
+    }
+    outcomes
+        .into_iter()
+        .map(|outcome| outcome.expect("every job should have an outcome"))
+        .collect()
+}
+
+fn watch<T: Send + 'static>(job: Job<T>, timeout: Option<Duration>) -> Outcome<T> {
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        let _ = sender.send(job());
+    });
+    // A panicking job drops the sender without sending anything
+    let result = match timeout {
+        Some(timeout) => receiver.recv_timeout(timeout),
+        None => receiver.recv().map_err(|_| RecvTimeoutError::Disconnected),
+    };
+    match result {
+        Ok(value) => Outcome::Done(value),
+        Err(RecvTimeoutError::Timeout) => Outcome::TimedOut,
+        Err(RecvTimeoutError::Disconnected) => Outcome::Panicked,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    #[test]
+    fn test_outcomes_in_job_order() {
+        let jobs: Vec<Job<u32>> = vec![
+            Box::new(|| 1),
+            Box::new(|| {
+                thread::sleep(Duration::from_secs(2));
+                2
+            }),
+            Box::new(|| panic!("job panicked on purpose")),
+            Box::new(|| {
+                thread::sleep(Duration::from_millis(20));
+                4
+            }),
+        ];
+        let outcomes = run(jobs, 2, Some(Duration::from_millis(500)));
+        let expected = vec![
+            Outcome::Done(1),
+            Outcome::TimedOut,
+            Outcome::Panicked,
+            Outcome::Done(4),
+        ];
+        assert_eq!(expected, outcomes);
+    }
+
+    #[test]
+    fn test_bounded_workers() {
+        let running = Arc::new(AtomicUsize::new(0));
+        let most = Arc::new(AtomicUsize::new(0));
+        let jobs: Vec<Job<()>> = (0..8)
+            .map(|_| {
+                let (running, most) = (Arc::clone(&running), Arc::clone(&most));
+                Box::new(move || {
+                    let now = running.fetch_add(1, Ordering::SeqCst) + 1;
+                    most.fetch_max(now, Ordering::SeqCst);
+                    thread::sleep(Duration::from_millis(20));
+                    running.fetch_sub(1, Ordering::SeqCst);
+                }) as Job<()>
+            })
+            .collect();
+        let outcomes = run(jobs, 3, None);
+        assert!(outcomes.iter().all(|outcome| *outcome == Outcome::Done(())));
+        assert!(most.load(Ordering::SeqCst) <= 3);
+        assert!(run(Vec::<Job<()>>::new(), 3, None).is_empty());
+    }
+}
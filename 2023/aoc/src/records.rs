@@ -0,0 +1,113 @@
+use aoc_core::Part;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::Path;
+
+// (year, day, part number)
+type Key = (u16, u8, u8);
+
+/// What a record file keeps on each line after the year, day and part
+pub trait Fields: fmt::Display + Sized {
+    /// Comment written at the top of the file
+    const HEADER: &'static str;
+    /// Layout of the fields for error messages, e.g. "<answer>"
+    const LAYOUT: &'static str;
+
+    fn parse(fields: &str) -> Option<Self>;
+}
+
+/// Text file of entries by year, day and part, in the order they were added
+#[derive(Debug, PartialEq)]
+pub struct Records<F> {
+    entries: BTreeMap<Key, Vec<F>>,
+}
+
+impl<F> Default for Records<F> {
+    fn default() -> Self {
+        Records {
+            entries: BTreeMap::new(),
+        }
+    }
+}
+
+impl<F: Fields> Records<F> {
+    /// Reads the file, which is empty until the first entry is saved
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match std::fs::read_to_string(path) {
+            Ok(text) => Records::parse(&text).map_err(|err| format!("{}: {}", path.display(), err)),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Records::default()),
+            Err(err) => Err(format!("cannot read {}: {}", path.display(), err)),
+        }
+    }
+
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut records = Records::default();
+        for (index, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (key, fields) = parse_entry(line).ok_or_else(|| {
+                format!(
+                    "line {}: expected '<year> <day> <part> {}'",
+                    index + 1,
+                    F::LAYOUT
+                )
+            })?;
+            records.entries.entry(key).or_default().push(fields);
+        }
+        Ok(records)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)
+                .map_err(|err| format!("cannot create {}: {}", dir.display(), err))?;
+        }
+        std::fs::write(path, self.to_string())
+            .map_err(|err| format!("cannot write {}: {}", path.display(), err))
+    }
+
+    pub fn entries(&self, year: u16, day: u8, part: Part) -> &[F] {
+        self.entries
+            .get(&(year, day, part.number()))
+            .map_or(&[], Vec::as_slice)
+    }
+
+    pub fn push(&mut self, year: u16, day: u8, part: Part, fields: F) {
+        let entries = self.entries.entry((year, day, part.number())).or_default();
+        entries.push(fields);
+    }
+
+    /// Replaces every earlier entry of the part
+    pub fn set(&mut self, year: u16, day: u8, part: Part, fields: F) {
+        self.entries
+            .insert((year, day, part.number()), vec![fields]);
+    }
+}
+
+// 2023 06 1 288
+fn parse_entry<F: Fields>(line: &str) -> Option<(Key, F)> {
+    let mut fields = line.splitn(4, char::is_whitespace);
+    let year = fields.next()?.parse().ok()?;
+    let day = fields.next()?.parse().ok()?;
+    let part = fields
+        .next()?
+        .parse()
+        .ok()
+        .filter(|part| matches!(part, 1 | 2))?;
+    let fields = F::parse(fields.next()?.trim())?;
+    Some(((year, day, part), fields))
+}
+
+impl<F: Fields> fmt::Display for Records<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", F::HEADER)?;
+        for ((year, day, part), entries) in &self.entries {
+            for fields in entries {
+                writeln!(f, "{} {:02} {} {}", year, day, part, fields)?;
+            }
+        }
+        Ok(())
+    }
+}
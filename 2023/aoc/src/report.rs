@@ -0,0 +1,304 @@
+use crate::answers::Verification;
+use aoc_core::{Allocations, Answer, Part};
+use std::fmt::{self, Write};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+const CSV_HEADER: &str =
+    "year,day,part,answer,parse_ns,solve_ns,allocs,alloc_bytes,peak_bytes,status,error";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportFormat {
+    Json,
+    Csv,
+}
+
+impl ReportFormat {
+    /// Format named by the extension of the report file
+    pub fn from_path(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()? {
+            "json" => Some(ReportFormat::Json),
+            "csv" => Some(ReportFormat::Csv),
+            _ => None,
+        }
+    }
+}
+
+/// Where `aoc run` writes its report and in which format
+#[derive(Debug, PartialEq)]
+pub struct Report {
+    pub path: PathBuf,
+    pub format: ReportFormat,
+}
+
+/// Outcome of running one part
+#[derive(Debug, PartialEq)]
+pub struct Record {
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    pub outcome: Result<Solved, Failure>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Solved {
+    pub answer: Answer,
+    pub parse: Duration,
+    pub solve: Duration,
+    pub allocations: Option<Allocations>, // When the runner counts allocations
+    pub verification: Verification,       // Against the confirmed answers
+}
+
+/// Reason a part has no answer
+#[derive(Debug, PartialEq)]
+pub enum Failure {
+    Error(String),
+    TimedOut(Duration),
+}
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Failure::Error(message) => write!(f, "{}", message),
+            Failure::TimedOut(timeout) => write!(f, "timed out after {}s", timeout.as_secs_f64()),
+        }
+    }
+}
+
+impl Record {
+    fn status(&self) -> &'static str {
+        match &self.outcome {
+            Ok(solved) => match solved.verification {
+                Verification::Pass => "pass",
+                Verification::Fail { .. } => "fail",
+                Verification::Missing => "unverified",
+            },
+            Err(Failure::Error(_)) => "error",
+            Err(Failure::TimedOut(_)) => "timeout",
+        }
+    }
+
+    // Values of the fields, which are left empty when not known
+    fn fields(&self) -> Fields {
+        match &self.outcome {
+            Ok(solved) => Fields {
+                answer: Some(solved.answer.to_string()),
+                numbers: [
+                    Some(solved.parse.as_nanos()),
+                    Some(solved.solve.as_nanos()),
+                    solved
+                        .allocations
+                        .map(|allocations| allocations.count.into()),
+                    solved
+                        .allocations
+                        .map(|allocations| allocations.bytes.into()),
+                    solved
+                        .allocations
+                        .map(|allocations| allocations.peak.into()),
+                ],
+                error: None,
+            },
+            Err(failure) => Fields {
+                answer: None,
+                numbers: [None; 5],
+                error: Some(failure.to_string()),
+            },
+        }
+    }
+}
+
+struct Fields {
+    answer: Option<String>,
+    numbers: [Option<u128>; 5], // Parse time, solve time, allocations, bytes and peak bytes
+    error: Option<String>,
+}
+
+impl Report {
+    pub fn write(&self, records: &[Record]) -> Result<(), String> {
+        let text = match self.format {
+            ReportFormat::Json => to_json(records),
+            ReportFormat::Csv => to_csv(records),
+        };
+        if let Some(dir) = self.path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            std::fs::create_dir_all(dir)
+                .map_err(|err| format!("cannot create {}: {}", dir.display(), err))?;
+        }
+        std::fs::write(&self.path, text)
+            .map_err(|err| format!("cannot write {}: {}", self.path.display(), err))
+    }
+}
+
+/// Array of records, with answers as strings since they may not fit a JSON number
+pub fn to_json(records: &[Record]) -> String {
+    let string = |text: Option<&str>| text.map_or("null".to_string(), json_string);
+    let number = |number: Option<u128>| number.map_or("null".to_string(), |n| n.to_string());
+    let mut json = String::from("[");
+    for (index, record) in records.iter().enumerate() {
+        let fields = record.fields();
+        let [parse, solve, allocs, bytes, peak] = fields.numbers.map(number);
+        let separator = if index == 0 { "" } else { "," };
+        let _ = write!(
+            json,
+            "{}\n  {{\"year\": {}, \"day\": {}, \"part\": {}, \"answer\": {}, \"parse_ns\": {}, \"solve_ns\": {}, \"allocs\": {}, \"alloc_bytes\": {}, \"peak_bytes\": {}, \"status\": \"{}\", \"error\": {}}}",
+            separator,
+            record.year,
+            record.day,
+            record.part.number(),
+            string(fields.answer.as_deref()),
+            parse,
+            solve,
+            allocs,
+            bytes,
+            peak,
+            record.status(),
+            string(fields.error.as_deref())
+        );
+    }
+    json.push_str("\n]\n");
+    json
+}
+
+fn json_string(text: &str) -> String {
+    let mut json = String::from("\"");
+    for c in text.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(json, "\\u{:04x}", c as u32);
+            }
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
+
+/// One line per record after a header line
+pub fn to_csv(records: &[Record]) -> String {
+    let mut csv = format!("{}\n", CSV_HEADER);
+    for record in records {
+        let fields = record.fields();
+        let number = |number: Option<u128>| number.map(|n| n.to_string()).unwrap_or_default();
+        let numbers: Vec<String> = fields.numbers.into_iter().map(number).collect();
+        let _ = writeln!(
+            csv,
+            "{},{},{},{},{},{},{}",
+            record.year,
+            record.day,
+            record.part.number(),
+            csv_field(fields.answer.as_deref().unwrap_or_default()),
+            numbers.join(","),
+            record.status(),
+            csv_field(&fields.error.unwrap_or_default())
+        );
+    }
+    csv
+}
+
+// Fields with separators, quotes or line breaks are quoted, with quotes doubled
+fn csv_field(text: &str) -> String {
+    match text.contains([',', '"', '\n', '\r']) {
+        true => format!("\"{}\"", text.replace('"', "\"\"")),
+        false => text.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn records() -> Vec<Record> {
+        let solved = |answer: Answer, verification| {
+            Ok(Solved {
+                answer,
+                parse: Duration::from_micros(12),
+                solve: Duration::from_nanos(3456),
+                allocations: None,
+                verification,
+            })
+        };
+        let counted = Ok(Solved {
+            answer: 288u32.into(),
+            parse: Duration::from_micros(12),
+            solve: Duration::from_nanos(3456),
+            allocations: Some(Allocations {
+                count: 3,
+                bytes: 2048,
+                peak: 1024,
+            }),
+            verification: Verification::Pass,
+        });
+        vec![
+            Record {
+                year: 2023,
+                day: 6,
+                part: Part::One,
+                outcome: counted,
+            },
+            Record {
+                year: 2023,
+                day: 6,
+                part: Part::Two,
+                outcome: solved(
+                    "a,\"b\"".into(),
+                    Verification::Fail {
+                        expected: "71503".to_string(),
+                    },
+                ),
+            },
+            Record {
+                year: 2023,
+                day: 7,
+                part: Part::One,
+                outcome: Err(Failure::Error(
+                    "line 2, column 1: invalid\thand".to_string(),
+                )),
+            },
+            Record {
+                year: 2023,
+                day: 8,
+                part: Part::Two,
+                outcome: Err(Failure::TimedOut(Duration::from_millis(2500))),
+            },
+        ]
+    }
+
+    #[test]
+    fn test_to_json() {
+        let expected = r#"[
+  {"year": 2023, "day": 6, "part": 1, "answer": "288", "parse_ns": 12000, "solve_ns": 3456, "allocs": 3, "alloc_bytes": 2048, "peak_bytes": 1024, "status": "pass", "error": null},
+  {"year": 2023, "day": 6, "part": 2, "answer": "a,\"b\"", "parse_ns": 12000, "solve_ns": 3456, "allocs": null, "alloc_bytes": null, "peak_bytes": null, "status": "fail", "error": null},
+  {"year": 2023, "day": 7, "part": 1, "answer": null, "parse_ns": null, "solve_ns": null, "allocs": null, "alloc_bytes": null, "peak_bytes": null, "status": "error", "error": "line 2, column 1: invalid\thand"},
+  {"year": 2023, "day": 8, "part": 2, "answer": null, "parse_ns": null, "solve_ns": null, "allocs": null, "alloc_bytes": null, "peak_bytes": null, "status": "timeout", "error": "timed out after 2.5s"}
+]
+"#;
+        assert_eq!(expected, to_json(&records()));
+        assert_eq!("[\n]\n", to_json(&[]));
+        assert_eq!(r#""\u0001\\""#, json_string("\u{1}\\"));
+    }
+
+    #[test]
+    fn test_to_csv() {
+        let expected = "\
+year,day,part,answer,parse_ns,solve_ns,allocs,alloc_bytes,peak_bytes,status,error
+2023,6,1,288,12000,3456,3,2048,1024,pass,
+2023,6,2,\"a,\"\"b\"\"\",12000,3456,,,,fail,
+2023,7,1,,,,,,,error,\"line 2, column 1: invalid\thand\"
+2023,8,2,,,,,,,timeout,timed out after 2.5s
+";
+        assert_eq!(expected, to_csv(&records()));
+    }
+
+    #[test]
+    fn test_format_from_path() {
+        let format = |path: &str| ReportFormat::from_path(Path::new(path));
+        assert_eq!(Some(ReportFormat::Json), format("reports/run.json"));
+        assert_eq!(Some(ReportFormat::Csv), format("run.csv"));
+        assert_eq!(None, format("run.txt"));
+        assert_eq!(None, format("run"));
+    }
+}
@@ -0,0 +1,194 @@
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+const TEMPLATE_DIR: &str = "template";
+const TEMPLATE_DAY: &str = "day00";
+
+#[derive(Debug)]
+pub enum ScaffoldError {
+    Exists(PathBuf),
+    Io { path: PathBuf, error: io::Error },
+    Unregistrable { path: PathBuf, reason: String },
+}
+
+impl fmt::Display for ScaffoldError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ScaffoldError::Exists(path) => write!(f, "{} already exists", path.display()),
+            ScaffoldError::Io { path, error } => write!(f, "{}: {}", path.display(), error),
+            ScaffoldError::Unregistrable { path, reason } => {
+                write!(f, "cannot register day in {}: {}", path.display(), reason)
+            }
+        }
+    }
+}
+
+// Formats how a day is listed in a registry
+type Entry = fn(u8) -> String;
+
+// Files listing every day, and how a day is listed in each of them
+const REGISTRIES: [(&str, Entry); 3] = [
+    ("Cargo.toml", |day| format!("    \"day{:02}\",", day)),
+    ("aoc/Cargo.toml", |day| {
+        format!("day{:02} = {{ path = \"../day{:02}\" }}", day, day)
+    }),
+    ("aoc/src/days.rs", |day| {
+        format!("    &day{:02}::Day{:02},", day, day)
+    }),
+];
+
+/// Creates the `dayNN` crate from the template and registers it with the workspace and runner
+pub fn new_day(workspace: &Path, day: u8) -> Result<PathBuf, ScaffoldError> {
+    let day_dir = workspace.join(format!("day{:02}", day));
+    if day_dir.exists() {
+        return Err(ScaffoldError::Exists(day_dir));
+    }
+
+    // Prepare every registry before touching the disk, so a failure leaves nothing half done
+    let registries = REGISTRIES
+        .iter()
+        .map(|(file, entry)| {
+            let path = workspace.join(file);
+            let text = read(&path)?;
+            let registered =
+                register(&text, day, entry).map_err(|reason| ScaffoldError::Unregistrable {
+                    path: path.clone(),
+                    reason,
+                })?;
+            Ok((path, registered))
+        })
+        .collect::<Result<Vec<(PathBuf, String)>, ScaffoldError>>()?;
+
+    copy_template(&workspace.join(TEMPLATE_DIR), &day_dir, day)?;
+    for (path, text) in registries {
+        write(&path, &text)?;
+    }
+    Ok(day_dir)
+}
+
+// Inserts the day's entry to the list of entries, keeping the days in order
+fn register(text: &str, day: u8, entry: &Entry) -> Result<String, String> {
+    let entry_day = |line: &str| (1..=25).find(|&day| line.trim_end() == entry(day));
+    let lines: Vec<&str> = text.split_inclusive('\n').collect();
+    let days: Vec<(usize, u8)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(index, line)| Some((index, entry_day(line)?)))
+        .collect();
+    if days.iter().any(|&(_, registered)| registered == day) {
+        return Err(format!("day {:02} is already registered", day));
+    }
+    let index = match days.iter().find(|&&(_, registered)| registered > day) {
+        Some(&(index, _)) => index,
+        None => match days.last() {
+            Some(&(index, _)) => index + 1,
+            None => return Err("no registered days to place the new day next to".to_string()),
+        },
+    };
+
+    let mut registered: Vec<String> = lines.iter().map(|line| line.to_string()).collect();
+    registered.insert(index, format!("{}\n", entry(day)));
+    Ok(registered.concat())
+}
+
+fn copy_template(template: &Path, target: &Path, day: u8) -> Result<(), ScaffoldError> {
+    let io_error = |path: &Path| {
+        let path = path.to_path_buf();
+        move |error| ScaffoldError::Io { path, error }
+    };
+    fs::create_dir_all(target).map_err(io_error(target))?;
+    for entry in fs::read_dir(template).map_err(io_error(template))? {
+        let entry = entry.map_err(io_error(template))?;
+        let source = entry.path();
+        let destination = target.join(entry.file_name());
+        if source.is_dir() {
+            // Build output is not part of the template
+            if entry.file_name() != "target" {
+                copy_template(&source, &destination, day)?;
+            }
+            continue;
+        }
+        write(&destination, &rewrite(&read(&source)?, day))?;
+    }
+    Ok(())
+}
+
+// Replaces the template's day markers with the new day
+fn rewrite(text: &str, day: u8) -> String {
+    text.replace(TEMPLATE_DAY, &format!("day{:02}", day))
+        .replace("Day00", &format!("Day{:02}", day))
+        .replace("const DAY: u8 = 0;", &format!("const DAY: u8 = {};", day))
+}
+
+fn read(path: &Path) -> Result<String, ScaffoldError> {
+    fs::read_to_string(path).map_err(|error| ScaffoldError::Io {
+        path: path.to_path_buf(),
+        error,
+    })
+}
+
+fn write(path: &Path, text: &str) -> Result<(), ScaffoldError> {
+    fs::write(path, text).map_err(|error| ScaffoldError::Io {
+        path: path.to_path_buf(),
+        error,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::temp::TempDir;
+
+    fn temp_workspace(name: &str) -> TempDir {
+        let workspace = TempDir::new(&format!("scaffold-{}", name));
+        let files = [
+            ("Cargo.toml", "members = [\n    \"aoc\",\n    \"day01\",\n    \"day03\",\n    \"template\",\n]\n"),
+            ("aoc/Cargo.toml", "[dependencies]\nday01 = { path = \"../day01\" }\nday03 = { path = \"../day03\" }\n"),
+            ("aoc/src/days.rs", "pub static PUZZLES: &[&dyn Puzzle] = &[\n    &day01::Day01,\n    &day03::Day03,\n];\n"),
+            ("template/Cargo.toml", "[package]\nname = \"day00\"\n"),
+            ("template/src/lib.rs", "pub struct Day00;\nconst DAY: u8 = 0;\n"),
+        ];
+        for (file, text) in files {
+            let path = workspace.join(file);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, text).unwrap();
+        }
+        fs::create_dir_all(workspace.join("day01")).unwrap();
+        workspace
+    }
+
+    #[test]
+    fn test_new_day() {
+        let workspace = temp_workspace("new");
+        let day_dir = new_day(&workspace, 2).unwrap();
+        let read = |file: &str| fs::read_to_string(workspace.join(file)).unwrap();
+        assert_eq!("[package]\nname = \"day02\"\n", read("day02/Cargo.toml"));
+        assert_eq!(
+            "pub struct Day02;\nconst DAY: u8 = 2;\n",
+            read("day02/src/lib.rs")
+        );
+        assert!(read("Cargo.toml").contains("\"day01\",\n    \"day02\",\n    \"day03\","));
+        assert!(read("aoc/Cargo.toml")
+            .ends_with("day02 = { path = \"../day02\" }\nday03 = { path = \"../day03\" }\n"));
+        assert!(read("aoc/src/days.rs").contains("&day02::Day02,\n    &day03::Day03,\n];"));
+
+        // Existing days are never overwritten
+        assert!(
+            matches!(new_day(&workspace, 2), Err(ScaffoldError::Exists(path)) if path == day_dir)
+        );
+        assert!(matches!(
+            new_day(&workspace, 1),
+            Err(ScaffoldError::Exists(_))
+        ));
+    }
+
+    #[test]
+    fn test_new_day_already_registered() {
+        let workspace = temp_workspace("registered");
+        let error = new_day(&workspace, 3).unwrap_err();
+        assert!(matches!(error, ScaffoldError::Unregistrable { .. }));
+        assert!(!workspace.join("day03").exists());
+    }
+}
@@ -0,0 +1,305 @@
+use crate::records::{Fields, Records};
+use aoc_core::Part;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// History of submitted answers in the state directory
+pub const GUESSES_FILE: &str = "guesses.txt";
+
+/// Server's response to a submitted answer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,          // Neither too high nor too low was told
+    Wait(Duration), // Submitted too soon after the previous answer
+    Completed,      // The part was already solved, so the answer was not checked
+}
+
+impl Verdict {
+    /// Reads the verdict from the HTML page answering the submission
+    pub fn parse(page: &str) -> Option<Self> {
+        if page.contains("That's the right answer") {
+            Some(Verdict::Correct)
+        } else if page.contains("your answer is too high") {
+            Some(Verdict::TooHigh)
+        } else if page.contains("your answer is too low") {
+            Some(Verdict::TooLow)
+        } else if page.contains("That's not the right answer") {
+            Some(Verdict::Wrong)
+        } else if page.contains("You gave an answer too recently") {
+            // "You have 1m 5s left to wait."
+            let (_, wait) = page.split_once("You have ")?;
+            let (wait, _) = wait.split_once(" left to wait")?;
+            parse_wait(wait).map(Verdict::Wait)
+        } else if page.contains("Did you already complete it?") {
+            Some(Verdict::Completed)
+        } else {
+            None
+        }
+    }
+
+    // Only verdicts about the answer itself are worth remembering
+    fn name(&self) -> Option<&'static str> {
+        match self {
+            Verdict::Correct => Some("correct"),
+            Verdict::TooHigh => Some("high"),
+            Verdict::TooLow => Some("low"),
+            Verdict::Wrong => Some("wrong"),
+            Verdict::Wait(_) | Verdict::Completed => None,
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        [
+            Verdict::Correct,
+            Verdict::TooHigh,
+            Verdict::TooLow,
+            Verdict::Wrong,
+        ]
+        .into_iter()
+        .find(|verdict| verdict.name() == Some(name))
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh => write!(f, "wrong, too high"),
+            Verdict::TooLow => write!(f, "wrong, too low"),
+            Verdict::Wrong => write!(f, "wrong"),
+            Verdict::Wait(wait) => write!(f, "submitted too soon, wait {}s", wait.as_secs()),
+            Verdict::Completed => write!(f, "not checked, the part is already completed"),
+        }
+    }
+}
+
+// 1h 2m 3s
+fn parse_wait(wait: &str) -> Option<Duration> {
+    let mut seconds = 0;
+    for amount in wait.split_whitespace() {
+        let (number, unit) = amount.split_at(amount.find(|c: char| !c.is_ascii_digit())?);
+        let number: u64 = number.parse().ok()?;
+        seconds += match unit {
+            "h" => number * 3600,
+            "m" => number * 60,
+            "s" => number,
+            _ => return None,
+        };
+    }
+    Some(Duration::from_secs(seconds))
+}
+
+/// Reason for not submitting an answer
+#[derive(Debug, PartialEq)]
+pub enum Refusal {
+    Solved { answer: String },
+    Submitted { verdict: Verdict },
+    TooHigh { bound: String }, // Answers at or above the bound are too high
+    TooLow { bound: String },
+}
+
+impl fmt::Display for Refusal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Refusal::Solved { answer } => write!(f, "the part was already solved with {}", answer),
+            Refusal::Submitted { verdict } => {
+                write!(f, "the answer was already submitted and was {}", verdict)
+            }
+            Refusal::TooHigh { bound } => write!(f, "{} was already too high", bound),
+            Refusal::TooLow { bound } => write!(f, "{} was already too low", bound),
+        }
+    }
+}
+
+/// Submitted answers and their verdicts by year, day and part
+pub type Guesses = Records<Guess>;
+
+/// Answer submitted for a part and the verdict it got
+#[derive(Debug, PartialEq)]
+pub struct Guess {
+    verdict: Verdict,
+    answer: String,
+}
+
+impl Fields for Guess {
+    const HEADER: &'static str = "\
+# Answers submitted by `aoc submit` and the verdicts they got
+# <year> <day> <part> <correct|high|low|wrong> <answer>
+";
+    const LAYOUT: &'static str = "<verdict> <answer>";
+
+    // high 300
+    fn parse(fields: &str) -> Option<Self> {
+        let (verdict, answer) = fields.split_once(char::is_whitespace)?;
+        let verdict = Verdict::from_name(verdict)?;
+        let answer = answer.trim();
+        (!answer.is_empty()).then(|| Guess {
+            verdict,
+            answer: answer.to_string(),
+        })
+    }
+}
+
+impl fmt::Display for Guess {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let verdict = self.verdict.name().unwrap_or_default();
+        write!(f, "{} {}", verdict, self.answer)
+    }
+}
+
+impl Guesses {
+    /// Remembers the verdict, unless it says nothing about the answer
+    pub fn record(&mut self, year: u16, day: u8, part: Part, answer: &str, verdict: Verdict) {
+        if verdict.name().is_some() {
+            let answer = answer.to_string();
+            self.push(year, day, part, Guess { verdict, answer });
+        }
+    }
+
+    /// Answer that the server confirmed as correct, if any
+    pub fn solved(&self, year: u16, day: u8, part: Part) -> Option<&str> {
+        self.entries(year, day, part)
+            .iter()
+            .find(|guess| guess.verdict == Verdict::Correct)
+            .map(|guess| guess.answer.as_str())
+    }
+
+    /// Checks that no earlier verdict already rules the answer out
+    pub fn check(&self, year: u16, day: u8, part: Part, answer: &str) -> Result<(), Refusal> {
+        if let Some(solved) = self.solved(year, day, part) {
+            return Err(Refusal::Solved {
+                answer: solved.to_string(),
+            });
+        }
+        let number = answer.parse::<i128>().ok();
+        for Guess {
+            verdict,
+            answer: guess,
+        } in self.entries(year, day, part)
+        {
+            if guess == answer {
+                return Err(Refusal::Submitted { verdict: *verdict });
+            }
+            // Bounds only apply when both answers are numbers
+            let (Some(number), Ok(bound)) = (number, guess.parse::<i128>()) else {
+                continue;
+            };
+            match verdict {
+                Verdict::TooHigh if number >= bound => {
+                    return Err(Refusal::TooHigh {
+                        bound: guess.clone(),
+                    })
+                }
+                Verdict::TooLow if number <= bound => {
+                    return Err(Refusal::TooLow {
+                        bound: guess.clone(),
+                    })
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+pub fn guesses_path(state_dir: &Path) -> PathBuf {
+    state_dir.join(GUESSES_FILE)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_verdict() {
+        let page = |text: &str| format!("<main>\n<article><p>{}</p></article>\n</main>", text);
+        let parse = |text: &str| Verdict::parse(&page(text));
+        assert_eq!(
+            Some(Verdict::Correct),
+            parse("That's the right answer! You are one gold star closer.")
+        );
+        assert_eq!(
+            Some(Verdict::TooHigh),
+            parse("That's not the right answer; your answer is too high.")
+        );
+        assert_eq!(
+            Some(Verdict::TooLow),
+            parse("That's not the right answer; your answer is too low.")
+        );
+        assert_eq!(
+            Some(Verdict::Wrong),
+            parse("That's not the right answer. If you're stuck, ...")
+        );
+        assert_eq!(
+            Some(Verdict::Wait(Duration::from_secs(65))),
+            parse("You gave an answer too recently. You have 1m 5s left to wait.")
+        );
+        assert_eq!(
+            Some(Verdict::Completed),
+            parse("You don't seem to be solving the right level.  Did you already complete it?")
+        );
+        assert_eq!(None, parse("Please log in."));
+    }
+
+    #[test]
+    fn test_check() {
+        let mut guesses = Guesses::default();
+        guesses.record(2023, 6, Part::One, "300", Verdict::TooHigh);
+        guesses.record(2023, 6, Part::One, "100", Verdict::TooLow);
+        guesses.record(2023, 6, Part::One, "250", Verdict::Wrong);
+        guesses.record(
+            2023,
+            6,
+            Part::One,
+            "260",
+            Verdict::Wait(Duration::from_secs(5)),
+        );
+        let check = |answer| guesses.check(2023, 6, Part::One, answer);
+        assert_eq!(Ok(()), check("288"));
+        assert_eq!(Ok(()), check("260"));
+        assert_eq!(
+            Err(Refusal::TooHigh {
+                bound: "300".to_string()
+            }),
+            check("301")
+        );
+        assert_eq!(
+            Err(Refusal::TooLow {
+                bound: "100".to_string()
+            }),
+            check("99")
+        );
+        assert_eq!(
+            Err(Refusal::Submitted {
+                verdict: Verdict::Wrong
+            }),
+            check("250")
+        );
+        assert_eq!(Ok(()), guesses.check(2023, 6, Part::Two, "301"));
+
+        assert_eq!(None, guesses.solved(2023, 6, Part::One));
+        guesses.record(2023, 6, Part::One, "288", Verdict::Correct);
+        assert_eq!(Some("288"), guesses.solved(2023, 6, Part::One));
+        let solved = guesses.check(2023, 6, Part::One, "289");
+        assert!(matches!(solved, Err(Refusal::Solved { .. })));
+    }
+
+    #[test]
+    fn test_parse_and_display() {
+        let mut guesses = Guesses::default();
+        guesses.record(2023, 6, Part::Two, "abc", Verdict::Wrong);
+        guesses.record(2023, 6, Part::One, "300", Verdict::TooHigh);
+        guesses.record(2023, 6, Part::One, "288", Verdict::Correct);
+        let expected = format!(
+            "{}2023 06 1 high 300\n2023 06 1 correct 288\n2023 06 2 wrong abc\n",
+            Guess::HEADER
+        );
+        assert_eq!(expected, guesses.to_string());
+        assert_eq!(guesses, Guesses::parse(&expected).unwrap());
+        assert!(Guesses::parse("2023 06 1 maybe 288").is_err());
+    }
+}
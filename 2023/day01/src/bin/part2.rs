@@ -1,7 +0,0 @@
-use day01::part2::solve;
-
-fn main() {
-    let input = include_str!("../../input1.txt");
-    let result = solve(input);
-    println!("{}", result);
-}
@@ -0,0 +1,45 @@
+use crate::part2::SPELLED_NUMBERS;
+use aoc_core::{Generated, Rng};
+
+const LINES: usize = 1000; // Official input size
+
+// None of these letters appear in a spelled out digit, so filler never forms one
+const FILLER: &[u8] = b"abcdjklmpqyz";
+
+// Lines of letters with digits and spelled out digits, between filler that keeps them apart
+pub fn generate(rng: &mut Rng, scale: usize) -> Generated {
+    let mut input = String::new();
+    let (mut part1, mut part2) = (0, 0);
+    for _ in 0..LINES * scale {
+        let mut digits: Vec<u32> = Vec::new(); // Written as numbers
+        let mut values: Vec<u32> = Vec::new(); // Written either way
+        for _ in 0..rng.range(1..6) {
+            push_filler(rng, &mut input);
+            let value = rng.range(1..10);
+            match rng.chance(0.5) {
+                true => {
+                    input.push(char::from_digit(value, 10).unwrap());
+                    digits.push(value);
+                }
+                false => input.push_str(SPELLED_NUMBERS[value as usize - 1].0),
+            }
+            values.push(value);
+        }
+        push_filler(rng, &mut input);
+        input.push('\n');
+
+        let calibration = |values: &[u32]| Some(values.first()? * 10 + values.last()?);
+        part1 += calibration(&digits).unwrap_or(0);
+        part2 += calibration(&values).unwrap_or(0);
+    }
+    Generated {
+        input,
+        answers: [Some(part1.into()), Some(part2.into())],
+    }
+}
+
+fn push_filler(rng: &mut Rng, line: &mut String) {
+    for _ in 0..rng.range(1..6) {
+        line.push(*rng.choose(FILLER) as char);
+    }
+}
@@ -1,2 +1,61 @@
+mod generate;
 pub mod part1;
 pub mod part2;
+
+aoc_core::fixture_tests!();
+
+use aoc_core::{Answer, Generated, ParseError, Rng, Solution};
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 1;
+    type Input<'a> = Vec<&'a str>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        input
+            .lines()
+            .map(|line| match contains_digit(line) {
+                true => Ok(line),
+                false => Err(ParseError::new(
+                    Self::DAY,
+                    input,
+                    line,
+                    "line has no digits",
+                )),
+            })
+            .collect()
+    }
+
+    fn part1(&self, input: &Self::Input<'_>) -> Answer {
+        part1::solve(input)
+    }
+
+    fn part2(&self, input: &Self::Input<'_>) -> Answer {
+        part2::solve(input)
+    }
+
+    fn generate(&self, rng: &mut Rng, scale: usize) -> Option<Generated> {
+        Some(generate::generate(rng, scale))
+    }
+}
+
+// Digits may also be spelled out with letters, which only count in part 2
+fn contains_digit(line: &str) -> bool {
+    line.chars().any(|c| c.is_ascii_digit())
+        || part2::SPELLED_NUMBERS
+            .iter()
+            .any(|(key, _)| line.contains(key))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_non_ascii_characters() {
+        let lines = Day01.parse("é1\nxtwoñ3").expect("valid input");
+        assert_eq!(Answer::from(11 + 23u32), Day01.part2(&lines));
+    }
+}
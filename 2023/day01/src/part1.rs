@@ -1,31 +1,16 @@
-pub fn solve(input: &str) -> String {
-    input
-        .lines()
-        .map(combine_first_and_last_number)
-        .sum::<u32>()
-        .to_string()
-}
+use aoc_core::Answer;
 
-fn combine_first_and_last_number(input: &str) -> u32 {
-    let digits: Vec<u32> = input
-        .chars()
-        .filter_map(|c| c.to_digit(10))
-        .collect();
-
-    digits[0] * 10 + digits.last().unwrap()
+pub fn solve(lines: &[&str]) -> Answer {
+    lines
+        .iter()
+        .filter_map(|line| combine_first_and_last_number(line))
+        .sum::<u32>()
+        .into()
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+// Lines with only spelled out digits have no calibration value in part 1
+fn combine_first_and_last_number(input: &str) -> Option<u32> {
+    let digits: Vec<u32> = input.chars().filter_map(|c| c.to_digit(10)).collect();
 
-    #[test]
-    fn test_solve() {
-        let input = "1abc2
-pqr3stu8vwx
-a1b2c3d4e5f
-treb7uchet";
-        let solution = "142";
-        assert_eq!(solution, solve(input));
-    }
+    Some(digits.first()? * 10 + digits.last()?)
 }
@@ -1,4 +1,6 @@
-static SPELLED_NUMBERS: &[(&str, u32)] = &[
+use aoc_core::Answer;
+
+pub(crate) static SPELLED_NUMBERS: &[(&str, u32)] = &[
     ("one", 1),
     ("two", 2),
     ("three", 3),
@@ -10,45 +12,29 @@ static SPELLED_NUMBERS: &[(&str, u32)] = &[
     ("nine", 9),
 ];
 
-pub fn solve(input: &str) -> String {
-    let result = input
-        .lines()
-        .map(combine_first_and_last_number)
+pub fn solve(lines: &[&str]) -> Answer {
+    let result = lines
+        .iter()
+        .map(|line| combine_first_and_last_number(line))
         .sum::<u32>();
 
-    result.to_string()
+    result.into()
 }
 
 fn combine_first_and_last_number(input: &str) -> u32 {
-    let mut numbers = (0..input.len()).filter_map(|i| {
+    let mut numbers = input.char_indices().filter_map(|(i, c)| {
         let remaining_line = &input[i..];
         for (key, value) in SPELLED_NUMBERS {
             if remaining_line.starts_with(key) {
                 return Some(value.to_owned());
             }
         }
-        remaining_line.chars().next().unwrap().to_digit(10)
+        c.to_digit(10)
     });
 
-    let first: u32 = numbers.next().unwrap();
-    let last: u32 = numbers.last().unwrap_or(first);
+    let first: u32 = numbers
+        .next()
+        .expect("parsing checks that every line has a digit");
+    let last: u32 = numbers.next_back().unwrap_or(first);
     first * 10 + last
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_solve() {
-        let input = "two1nine
-eightwothree
-abcone2threexyz
-xtwone3four
-4nineeightseven2
-zoneight234
-7pqrstsixteen";
-        let solution = "281";
-        assert_eq!(solution, solve(input));
-    }
-}
@@ -0,0 +1,39 @@
+use aoc_core::{Generated, Rng};
+
+const GAMES: usize = 100; // Official input size
+const COLORS: [&str; 3] = ["red", "green", "blue"];
+const BAG: [u32; 3] = [12, 13, 14]; // Cubes of each color in part 1
+
+// Games of a few sets, each revealing some of the colors
+pub fn generate(rng: &mut Rng, scale: usize) -> Generated {
+    let mut input = String::new();
+    let (mut part1, mut part2) = (0, 0);
+    for id in 1..=GAMES * scale {
+        let mut fewest = [0; 3]; // Fewest cubes of each color the game is possible with
+        let sets: Vec<String> = (0..rng.range(1..7))
+            .map(|_| {
+                let mut colors = [0, 1, 2];
+                rng.shuffle(&mut colors);
+                let cubes: Vec<String> = colors[..rng.range(1..4)]
+                    .iter()
+                    .map(|&color| {
+                        let amount = rng.range(1..21);
+                        fewest[color] = fewest[color].max(amount);
+                        format!("{} {}", amount, COLORS[color])
+                    })
+                    .collect();
+                cubes.join(", ")
+            })
+            .collect();
+        input.push_str(&format!("Game {}: {}\n", id, sets.join("; ")));
+
+        if fewest.iter().zip(BAG).all(|(&amount, bag)| amount <= bag) {
+            part1 += id;
+        }
+        part2 += fewest.iter().product::<u32>() as usize;
+    }
+    Generated {
+        input,
+        answers: [Some(part1.into()), Some(part2.into())],
+    }
+}
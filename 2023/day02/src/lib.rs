@@ -1,2 +1,107 @@
+mod generate;
 pub mod part1;
 pub mod part2;
+
+aoc_core::fixture_tests!();
+
+use aoc_core::{Answer, Generated, ParseError, Rng, Solution};
+use std::fmt;
+
+pub struct Day02;
+
+pub struct Game {
+    id: u32,
+    cubes: Vec<Cubes>, // Cubes from every set of the game
+}
+
+pub struct Cubes {
+    amount: u32,
+    color: Color,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Color {
+    Red,
+    Green,
+    Blue,
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Color::Red => "red",
+            Color::Green => "green",
+            Color::Blue => "blue",
+        };
+        f.write_str(name)
+    }
+}
+
+impl Solution for Day02 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 2;
+    type Input<'a> = Vec<Game>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        input.lines().map(|line| parse_game(input, line)).collect()
+    }
+
+    fn part1(&self, input: &Self::Input<'_>) -> Answer {
+        part1::solve(input)
+    }
+
+    fn part2(&self, input: &Self::Input<'_>) -> Answer {
+        part2::solve(input)
+    }
+
+    fn generate(&self, rng: &mut Rng, scale: usize) -> Option<Generated> {
+        Some(generate::generate(rng, scale))
+    }
+}
+
+fn parse_game(input: &str, line: &str) -> Result<Game, ParseError> {
+    // Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+    let error = |span: &str, message: &str| ParseError::new(Day02::DAY, input, span, message);
+    let (game, sets) = line
+        .split_once(':')
+        .ok_or_else(|| error(line, "game ID should end with ':'"))?;
+    let id: u32 = game
+        .strip_prefix("Game ")
+        .and_then(|id| id.parse().ok())
+        .ok_or_else(|| error(game, "game ID should be a number"))?;
+    let cubes = sets
+        .split(&[',', ';'])
+        .map(|cubes| parse_cubes(input, cubes))
+        .collect::<Result<Vec<Cubes>, ParseError>>()?;
+    Ok(Game { id, cubes })
+}
+
+fn parse_cubes(input: &str, cubes: &str) -> Result<Cubes, ParseError> {
+    let error = |span: &str, message: &str| ParseError::new(Day02::DAY, input, span, message);
+    let (amount, color) = cubes
+        .trim()
+        .split_once(' ')
+        .ok_or_else(|| error(cubes, "expected number of cubes and their color"))?;
+    let amount: u32 = amount
+        .parse()
+        .map_err(|_| error(amount, "number of cubes should be a number"))?;
+    let color: Color = match color {
+        "red" => Color::Red,
+        "green" => Color::Green,
+        "blue" => Color::Blue,
+        _ => return Err(error(color, "cubes are red, green or blue")),
+    };
+    Ok(Cubes { amount, color })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_unknown_color() {
+        let input = "Game 1: 3 blue, 4 red\nGame 2: 1 blue, 2 purple";
+        let error = Day02.parse(input).err().expect("parse error");
+        assert_eq!((2, 19), (error.line, error.column));
+    }
+}
@@ -0,0 +1,62 @@
+use aoc_core::random::scaled_side;
+use aoc_core::{Generated, Grid, Rng};
+use std::collections::HashMap;
+
+const SIZE: usize = 140; // Official input is a square of this size
+const SYMBOLS: &[u8] = b"*#+$/=%@&-";
+
+// Schematic of numbers with symbols scattered between them, some of them gears
+pub fn generate(rng: &mut Rng, scale: usize) -> Generated {
+    let size = scaled_side(SIZE, scale);
+    let mut schematic = Grid::filled(size, size, '.');
+    let mut numbers: Vec<(usize, usize, usize, u32)> = Vec::new(); // Row, column, digits, value
+    for row in 0..size {
+        let mut col = rng.range(0..4);
+        while col < size {
+            if rng.chance(0.1) {
+                schematic[(row, col)] = *rng.choose(SYMBOLS) as char;
+                col += rng.range(2..6);
+                continue;
+            }
+            // Numbers are separated by at least one dot, so they never merge
+            let digits = rng.range(1..4).min(size - col);
+            let number = rng.range(10u32.pow(digits as u32 - 1)..10u32.pow(digits as u32));
+            for (offset, digit) in number.to_string().chars().enumerate() {
+                schematic[(row, col + offset)] = digit;
+            }
+            numbers.push((row, col, digits, number));
+            col += digits + rng.range(1..8);
+        }
+    }
+
+    // Symbols on the next row are drawn after the number, so its neighbours are only known
+    // once the whole schematic is
+    let mut part_numbers = 0;
+    let mut gears: HashMap<(usize, usize), Vec<u32>> = HashMap::new();
+    for &(row, col, digits, number) in &numbers {
+        let rows = row.saturating_sub(1)..(row + 2).min(size);
+        let cols = col.saturating_sub(1)..(col + digits + 1).min(size);
+        let symbols: Vec<(usize, usize)> = rows
+            .flat_map(|row| cols.clone().map(move |col| (row, col)))
+            .filter(|&position| {
+                let cell = schematic[position];
+                cell != '.' && !cell.is_ascii_digit()
+            })
+            .collect();
+        if !symbols.is_empty() {
+            part_numbers += number;
+        }
+        for position in symbols.into_iter().filter(|&p| schematic[p] == '*') {
+            gears.entry(position).or_default().push(number);
+        }
+    }
+    let gear_ratios: u32 = gears
+        .values()
+        .filter(|numbers| numbers.len() == 2)
+        .map(|numbers| numbers[0] * numbers[1])
+        .sum();
+    Generated {
+        input: format!("{}\n", schematic),
+        answers: [Some(part_numbers.into()), Some(gear_ratios.into())],
+    }
+}
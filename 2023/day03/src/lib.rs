@@ -1,2 +1,31 @@
+mod generate;
 pub mod part1;
 pub mod part2;
+
+aoc_core::fixture_tests!();
+
+use aoc_core::{Answer, Generated, Grid, ParseError, Rng, Solution};
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 3;
+    type Input<'a> = Grid<char>; // The engine schematic
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        Grid::from_chars(input.trim_end(), Ok).map_err(|err| err.into_parse_error(Self::DAY, input))
+    }
+
+    fn part1(&self, input: &Self::Input<'_>) -> Answer {
+        part1::solve(input)
+    }
+
+    fn part2(&self, input: &Self::Input<'_>) -> Answer {
+        part2::solve(input)
+    }
+
+    fn generate(&self, rng: &mut Rng, scale: usize) -> Option<Generated> {
+        Some(generate::generate(rng, scale))
+    }
+}
@@ -1,5 +1,6 @@
+use aoc_core::grid::Position;
+use aoc_core::{Answer, Grid};
 use std::collections::HashSet;
-use regex::Regex;
 
 #[derive(Debug, Clone)]
 struct Positioned {
@@ -8,105 +9,68 @@ struct Positioned {
     value: String,
 }
 
-pub fn solve(input: &str) -> String {
-    let numbers: Vec<Positioned> = find_by_pattern(input, r"\d+");
-    let asterisks: Vec<Positioned> = find_by_pattern(input, r"\*");
+pub fn solve(grid: &Grid<char>) -> Answer {
+    let numbers: Vec<Positioned> = find_numbers(grid);
 
-    asterisks
-        .iter()
-        .filter_map(|asterisk| product_of_two_gears(asterisk, &numbers))
+    grid.positions(|&c| c == '*')
+        .filter_map(|asterisk| product_of_two_gears(grid, asterisk, &numbers))
         .sum::<u32>()
-        .to_string()
+        .into()
 }
 
-fn find_by_pattern(input: &str, pattern: &str) -> Vec<Positioned> {
-    let number_pattern = Regex::new(pattern).expect("valid pattern");
-
-    number_pattern
-        .captures_iter(input)
-        .map(|mat| {
-            let value: String = mat[0].to_string();
-            let index: usize = mat.get(0).expect("match").start();
-            let (row, col) = find_position(input, index);
-            Positioned { row, col, value }
-        })
-        .collect()
+fn find_numbers(grid: &Grid<char>) -> Vec<Positioned> {
+    let mut numbers: Vec<Positioned> = Vec::new();
+    for (row, line) in grid.rows().enumerate() {
+        let mut col = 0;
+        while col < line.len() {
+            // Take all consecutive digits starting from the column
+            let digits = line[col..]
+                .iter()
+                .take_while(|c| c.is_ascii_digit())
+                .count();
+            if digits == 0 {
+                col += 1;
+                continue;
+            }
+            let value: String = line[col..col + digits].iter().collect();
+            numbers.push(Positioned { row, col, value });
+            col += digits;
+        }
+    }
+    numbers
 }
 
-fn product_of_two_gears(asterisk: &Positioned, numbers: &[Positioned]) -> Option<u32> {
-    let touching_numbers = find_touching_numbers(asterisk.row, asterisk.col, numbers);
+fn product_of_two_gears(
+    grid: &Grid<char>,
+    asterisk: Position,
+    numbers: &[Positioned],
+) -> Option<u32> {
+    let touching_numbers = find_touching_numbers(grid, asterisk, numbers);
 
     // Only calculate product if the asterisk is touched by 2 numbers
     if touching_numbers.len() == 2 {
-        return Some(touching_numbers.iter().product::<u32>());
+        return touching_numbers
+            .iter()
+            .map(|&index| numbers[index].value.parse::<u32>().ok())
+            .product();
     }
     None
 }
 
-fn find_touching_numbers(row: usize, col: usize, numbers: &[Positioned]) -> HashSet<u32> {
-    let neighbors = [
-        (row.checked_sub(1), Some(col)),          // Up
-        (row.checked_sub(1), col.checked_sub(1)), // Up-Left
-        (row.checked_sub(1), col.checked_add(1)), // Up-Right
-        (row.checked_add(1), Some(col)),          // Down
-        (row.checked_add(1), col.checked_sub(1)), // Down-Left
-        (row.checked_add(1), col.checked_add(1)), // Down-Right
-        (Some(row), col.checked_sub(1)),          // Left
-        (Some(row), col.checked_add(1)),          // Right
-    ];
-
-    // Filter all touching numbers
-    neighbors
-        .iter()
-        .filter_map(|&(maybe_row, maybe_col)| {
-            match (maybe_row, maybe_col) {
-                (Some(row), Some(col)) => get_number_from_position(row, col, numbers),
-                _ => None,
-            }
-        })
+// Indices of the numbers, since two numbers touching the same asterisk may be equal
+fn find_touching_numbers(
+    grid: &Grid<char>,
+    asterisk: Position,
+    numbers: &[Positioned],
+) -> HashSet<usize> {
+    grid.neighbors8(asterisk)
+        .filter_map(|(row, col)| get_number_from_position(row, col, numbers))
         .collect()
 }
 
-fn get_number_from_position(row: usize, col: usize, numbers: &[Positioned]) -> Option<u32> {
-    numbers
-        .iter()
-        .find(|number| {
-            // Find the possible number at the given position
-            row == number.row && col >= number.col && col < number.col + number.value.len()
-        })
-        .and_then(|number| number.value.parse::<u32>().ok())
-}
-
-// Helper function to find the position (row, col) of a character index in a multiline string
-fn find_position(input: &str, index: usize) -> (usize, usize) {
-    input
-        .chars()
-        .take(index)
-        .fold((0, 0), |(row, col), character| {
-            match character {
-                '\n' => (row + 1, 0),
-                _ => (row, col + 1),
-            }
-        })
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_solve() {
-        let input = "467..114..
-...*......
-..35..633.
-......#...
-617*......
-.....+.58.
-..592.....
-......755.
-...$.*....
-.664.598..";
-        let solution = "467835";
-        assert_eq!(solution, solve(input));
-    }
+fn get_number_from_position(row: usize, col: usize, numbers: &[Positioned]) -> Option<usize> {
+    numbers.iter().position(|number| {
+        // Find the possible number at the given position
+        row == number.row && col >= number.col && col < number.col + number.value.len()
+    })
 }
@@ -1,7 +0,0 @@
-use day04::part2::solve;
-
-fn main() {
-    let input = include_str!("../../input.txt");
-    let result = solve(input);
-    println!("{}", result);
-}
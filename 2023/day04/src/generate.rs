@@ -0,0 +1,59 @@
+use aoc_core::{Generated, Rng};
+
+const CARDS: usize = 200; // Official input size
+const MY_NUMBERS: usize = 10;
+const WINNING_NUMBERS: usize = 25;
+
+// Cards only win copies of cards within their block, which keeps the copies from growing
+// exponentially with the number of cards
+const BLOCK: usize = 12;
+
+// Scratchcards with the number of matches picked first and the numbers drawn to match it
+pub fn generate(rng: &mut Rng, scale: usize) -> Generated {
+    let cards = CARDS * scale;
+    let width = cards.to_string().len();
+    let mut input = String::new();
+    let mut matches: Vec<usize> = Vec::with_capacity(cards);
+    for card in 0..cards {
+        let following = BLOCK - 1 - card % BLOCK;
+        let count = rng.range(0..following.min(cards - 1 - card).min(MY_NUMBERS) + 1);
+        matches.push(count);
+
+        let mut numbers: Vec<u32> = (1..100).collect();
+        rng.shuffle(&mut numbers);
+        let (mine, others) = numbers.split_at(MY_NUMBERS);
+        let mut winning: Vec<u32> = mine[..count].to_vec();
+        winning.extend(&others[..WINNING_NUMBERS - count]);
+        rng.shuffle(&mut winning);
+
+        let section = |numbers: &[u32]| -> Vec<String> {
+            numbers
+                .iter()
+                .map(|number| format!("{:2}", number))
+                .collect()
+        };
+        input.push_str(&format!(
+            "Card {:width$}: {} | {}\n",
+            card + 1,
+            section(mine).join(" "),
+            section(&winning).join(" "),
+            width = width
+        ));
+    }
+
+    let points: u32 = matches
+        .iter()
+        .filter(|&&count| count > 0)
+        .map(|&count| 1 << (count - 1))
+        .sum();
+    let mut copies = vec![1u32; cards];
+    for (card, &count) in matches.iter().enumerate() {
+        for next in card + 1..=card + count {
+            copies[next] += copies[card];
+        }
+    }
+    Generated {
+        input,
+        answers: [Some(points.into()), Some(copies.iter().sum::<u32>().into())],
+    }
+}
@@ -1,2 +1,66 @@
+mod generate;
 pub mod part1;
 pub mod part2;
+
+aoc_core::fixture_tests!();
+
+use aoc_core::numbers::parse_labeled_sections;
+use aoc_core::{Answer, Generated, ParseError, Rng, Solution};
+
+pub struct Day04;
+
+pub struct Scratchcard {
+    my_numbers: Vec<u32>,
+    winning_numbers: Vec<u32>,
+}
+
+impl Scratchcard {
+    // Calculate how many numbers were in my numbers compared to the winning numbers
+    fn common_number_count(&self) -> u32 {
+        self.my_numbers
+            .iter()
+            .filter(|num| self.winning_numbers.contains(num))
+            .count() as u32
+    }
+}
+
+impl Solution for Day04 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 4;
+    type Input<'a> = Vec<Scratchcard>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        input
+            .lines()
+            .map(|line| parse_scratchcard(input, line))
+            .collect()
+    }
+
+    fn part1(&self, input: &Self::Input<'_>) -> Answer {
+        part1::solve(input)
+    }
+
+    fn part2(&self, input: &Self::Input<'_>) -> Answer {
+        part2::solve(input)
+    }
+
+    fn generate(&self, rng: &mut Rng, scale: usize) -> Option<Generated> {
+        Some(generate::generate(rng, scale))
+    }
+}
+
+fn parse_scratchcard(input: &str, line: &str) -> Result<Scratchcard, ParseError> {
+    // Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+    let (_, sections) =
+        parse_labeled_sections(line).map_err(|err| err.into_parse_error(Day04::DAY, input))?;
+    match <[Vec<u32>; 2]>::try_from(sections) {
+        Ok([my_numbers, winning_numbers]) => Ok(Scratchcard {
+            my_numbers,
+            winning_numbers,
+        }),
+        Err(_) => {
+            let message = "expected two sections of numbers separated by '|'";
+            Err(ParseError::new(Day04::DAY, input, line, message))
+        }
+    }
+}
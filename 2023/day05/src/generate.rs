@@ -0,0 +1,109 @@
+use aoc_core::{Answer, Generated, Rng};
+
+const SEED_RANGES: usize = 10; // Official input size
+const MAP_RANGES: usize = 30;
+const CATEGORIES: [&str; 7] = [
+    "seed-to-soil",
+    "soil-to-fertilizer",
+    "fertilizer-to-water",
+    "water-to-light",
+    "light-to-temperature",
+    "temperature-to-humidity",
+    "humidity-to-location",
+];
+
+// Every number stays below this, so conversions fit the u32 the solution works with
+const LIMIT: u32 = 4_000_000_000;
+
+// Almanac whose maps shuffle consecutive ranges around, so the ranges of a map never overlap
+// and every map is a one-to-one conversion
+pub fn generate(rng: &mut Rng, scale: usize) -> Generated {
+    let mut bounds = cut_points(rng, SEED_RANGES * scale * 2);
+    let mut seed_ranges: Vec<(u32, u32)> = bounds
+        .chunks_exact(2)
+        .map(|range| (range[0], range[1] - range[0]))
+        .collect();
+    rng.shuffle(&mut seed_ranges);
+    let seeds: Vec<String> = seed_ranges
+        .iter()
+        .map(|(start, length)| format!("{} {}", start, length))
+        .collect();
+    let mut input = format!("seeds: {}\n", seeds.join(" "));
+
+    // Part 1 takes every number of the seed line as a seed, part 2 the ranges they make
+    let mut seed_numbers: Vec<u32> = seed_ranges
+        .iter()
+        .flat_map(|&(start, length)| [start, length])
+        .collect();
+    let mut ranges: Vec<(u32, u32)> = seed_ranges
+        .iter()
+        .map(|&(start, length)| (start, start + length))
+        .collect();
+    for category in CATEGORIES {
+        bounds = cut_points(rng, MAP_RANGES * scale - 1);
+        bounds.insert(0, 0);
+        bounds.push(LIMIT);
+        let sources: Vec<(u32, u32)> = bounds
+            .windows(2)
+            .map(|range| (range[0], range[1]))
+            .collect();
+        let mut order: Vec<usize> = (0..sources.len()).collect();
+        rng.shuffle(&mut order);
+
+        // Lay the source ranges out again in the shuffled order
+        let mut destination = 0;
+        let mut pieces: Vec<Piece> = Vec::new();
+        let mut lines: Vec<String> = Vec::new();
+        for index in order {
+            let (start, end) = sources[index];
+            if destination != start {
+                lines.push(format!("{} {} {}", destination, start, end - start));
+            }
+            pieces.push((start, end, destination));
+            destination += end - start;
+        }
+        rng.shuffle(&mut lines);
+        input.push_str(&format!("\n{} map:\n{}\n", category, lines.join("\n")));
+
+        // The pieces cover every number below the limit, so each number and each part of a
+        // range is in exactly one of them
+        for number in &mut seed_numbers {
+            let &(start, _, destination) = pieces
+                .iter()
+                .find(|&&(start, end, _)| (start..end).contains(number))
+                .expect("the pieces cover every number");
+            *number = destination + (*number - start);
+        }
+        ranges = ranges
+            .iter()
+            .flat_map(|&(first, last)| {
+                pieces.iter().filter_map(move |&(start, end, destination)| {
+                    let (from, to) = (first.max(start), last.min(end));
+                    (from < to).then(|| (destination + (from - start), destination + (to - start)))
+                })
+            })
+            .collect();
+    }
+    let part1 = seed_numbers.into_iter().min();
+    let part2 = ranges.into_iter().map(|(start, _)| start).min();
+    Generated {
+        input,
+        answers: [part1.map(Answer::from), part2.map(Answer::from)],
+    }
+}
+
+// Source range of a map and where its start goes
+type Piece = (u32, u32, u32);
+
+// Distinct numbers within the limit, in increasing order
+fn cut_points(rng: &mut Rng, count: usize) -> Vec<u32> {
+    let mut points: Vec<u32> = Vec::with_capacity(count);
+    while points.len() < count {
+        points.push(rng.range(1..LIMIT));
+        if points.len() == count {
+            points.sort();
+            points.dedup();
+        }
+    }
+    points
+}
@@ -1,2 +1,142 @@
+mod generate;
 pub mod part1;
 pub mod part2;
+
+aoc_core::fixture_tests!();
+
+use aoc_core::numbers::parse_numbers;
+use aoc_core::{Answer, Generated, Interval, ParseError, PiecewiseMap, Rng, Solution};
+use core::str::Lines;
+
+pub struct Day05;
+
+pub struct Almanac {
+    seeds: Vec<u32>,
+    conversion_maps: Vec<PiecewiseMap>, // From seed-to-soil to humidity-to-location
+}
+
+impl Solution for Day05 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 5;
+    type Input<'a> = Almanac;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        let mut lines: Lines = input.lines();
+
+        // Parse seeds
+        let seeds = parse_seeds(input, lines.next().unwrap_or_default())?;
+        lines.next(); // Skip empty line after seeds
+
+        let conversion_maps = generate_conversion_maps(input, &mut lines)?;
+        Ok(Almanac {
+            seeds,
+            conversion_maps,
+        })
+    }
+
+    fn part1(&self, input: &Self::Input<'_>) -> Answer {
+        part1::solve(input)
+    }
+
+    fn part2(&self, input: &Self::Input<'_>) -> Answer {
+        part2::solve(input)
+    }
+
+    fn generate(&self, rng: &mut Rng, scale: usize) -> Option<Generated> {
+        Some(generate::generate(rng, scale))
+    }
+}
+
+fn generate_conversion_maps(
+    input: &str,
+    lines: &mut Lines,
+) -> Result<Vec<PiecewiseMap>, ParseError> {
+    let conversion_categories = [
+        "seed-to-soil",
+        "soil-to-fertilizer",
+        "fertilizer-to-water",
+        "water-to-light",
+        "light-to-temperature",
+        "temperature-to-humidity",
+        "humidity-to-location",
+    ];
+
+    // Parse conversion maps for each category
+    conversion_categories
+        .iter()
+        .map(|category| {
+            // Check the category header, "seed-to-soil map:"
+            let header = lines.next().unwrap_or(&input[input.len()..]);
+            if header.strip_suffix(" map:") != Some(category) {
+                let message = format!("expected '{} map:' header", category);
+                return Err(ParseError::new(Day05::DAY, input, header, message));
+            }
+            parse_conversion_maps(input, lines)
+        })
+        .collect()
+}
+
+fn parse_seeds(input: &str, line: &str) -> Result<Vec<u32>, ParseError> {
+    let numbers_section = line
+        .strip_prefix("seeds:")
+        .ok_or_else(|| ParseError::new(Day05::DAY, input, line, "expected 'seeds:' line"))?;
+    let seeds: Vec<u32> =
+        parse_numbers(numbers_section).map_err(|err| err.into_parse_error(Day05::DAY, input))?;
+    if seeds.is_empty() {
+        let message = "'seeds:' line should list at least one seed";
+        return Err(ParseError::new(Day05::DAY, input, line, message));
+    }
+    Ok(seeds)
+}
+
+fn parse_conversion_maps(input: &str, lines: &mut Lines) -> Result<PiecewiseMap, ParseError> {
+    // The first line that covers a number converts it
+    let mut map = PiecewiseMap::new();
+    for line in lines.take_while(|line| !line.is_empty()) {
+        let numbers: Vec<i64> =
+            parse_numbers(line).map_err(|err| err.into_parse_error(Day05::DAY, input))?;
+        let [destination_start, source_start, length] = numbers[..] else {
+            let message = "conversion map line should have three numbers";
+            return Err(ParseError::new(Day05::DAY, input, line, message));
+        };
+        let source = Interval::with_length(source_start, length);
+        map.insert(source, destination_start - source_start);
+    }
+    Ok(map)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_short_conversion_map() {
+        let input = "seeds: 79 14\n\nseed-to-soil map:\n50 98\n";
+        let error = Day05.parse(input).err().expect("parse error");
+        assert_eq!(
+            (4, 1, "50 98"),
+            (error.line, error.column, error.snippet.as_str())
+        );
+    }
+
+    #[test]
+    fn test_parse_no_seeds() {
+        let error = Day05.parse("seeds:\n").err().expect("parse error");
+        assert_eq!((1, "seeds:"), (error.line, error.snippet.as_str()));
+    }
+
+    #[test]
+    fn test_odd_number_of_seeds() {
+        // Maps without lines leave every number as it is
+        let headers = "seed-to-soil soil-to-fertilizer fertilizer-to-water water-to-light \
+                       light-to-temperature temperature-to-humidity humidity-to-location";
+        let maps: Vec<String> = headers
+            .split(' ')
+            .map(|category| format!("{} map:\n", category))
+            .collect();
+        let input = format!("seeds: 79 14 55\n\n{}", maps.join("\n"));
+        let almanac = Day05.parse(&input).expect("valid almanac");
+        assert_eq!(Answer::from(14i64), Day05.part1(&almanac));
+        assert_eq!(Answer::Unsolved, Day05.part2(&almanac));
+    }
+}
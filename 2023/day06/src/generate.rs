@@ -0,0 +1,38 @@
+use aoc_core::{Generated, Rng};
+
+const RACES: usize = 4;
+const MAX_TIME: u64 = 100; // Official race times have two digits
+
+// Races where the record was set by holding the button for a known time, so exactly the
+// holds between it and its mirror beat the record. Part 2 reads all the numbers of a line
+// as one, so the scale makes the races longer rather than more of them.
+pub fn generate(rng: &mut Rng, scale: usize) -> Generated {
+    let mut times: Vec<u64> = Vec::with_capacity(RACES);
+    let mut distances: Vec<u64> = Vec::with_capacity(RACES);
+    let mut ways: u128 = 1;
+    for _ in 0..RACES {
+        let time = rng.range(7..MAX_TIME * scale as u64);
+        let hold = rng.range(1..time / 2);
+        times.push(time);
+        distances.push(hold * (time - hold));
+        ways *= u128::from(time - 2 * hold - 1);
+    }
+
+    // Numbers are aligned in columns as wide as the longest record
+    let width = distances
+        .iter()
+        .max()
+        .map_or(1, |record| record.to_string().len())
+        + 1;
+    let line = |label: &str, numbers: &[u64]| -> String {
+        let numbers: Vec<String> = numbers
+            .iter()
+            .map(|number| format!("{:>width$}", number, width = width))
+            .collect();
+        format!("{:<9}{}\n", label, numbers.join("  "))
+    };
+    Generated {
+        input: line("Time:", &times) + &line("Distance:", &distances),
+        answers: [Some(ways.into()), None],
+    }
+}
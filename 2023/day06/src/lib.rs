@@ -1,2 +1,82 @@
+mod generate;
 pub mod part1;
 pub mod part2;
+
+aoc_core::fixture_tests!();
+
+use aoc_core::numbers::{parse_kerned, parse_numbers};
+use aoc_core::{Answer, Generated, ParseError, Rng, Solution, SpanError};
+
+pub struct Day06;
+
+pub struct RaceRecords {
+    times: Vec<u64>,
+    distances: Vec<u64>,
+    kerned_time: u128, // All the times read as one number
+    kerned_distance: u128,
+}
+
+impl Solution for Day06 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 6;
+    type Input<'a> = RaceRecords;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        let mut lines = input.lines();
+        let mut number_section = |label: &str| {
+            let line = lines.next().unwrap_or(&input[input.len()..]);
+            line.strip_prefix(label).ok_or_else(|| {
+                let message = format!("expected line starting with '{}'", label);
+                ParseError::new(Self::DAY, input, line, message)
+            })
+        };
+
+        let times = number_section("Time:")?;
+        let distances = number_section("Distance:")?;
+        let error = |err: SpanError| err.into_parse_error(Self::DAY, input);
+        Ok(RaceRecords {
+            times: parse_numbers(times).map_err(error)?,
+            distances: parse_numbers(distances).map_err(error)?,
+            kerned_time: parse_kerned(times).map_err(error)?,
+            kerned_distance: parse_kerned(distances).map_err(error)?,
+        })
+    }
+
+    fn part1(&self, input: &Self::Input<'_>) -> Answer {
+        part1::solve(input)
+    }
+
+    fn part2(&self, input: &Self::Input<'_>) -> Answer {
+        part2::solve(input)
+    }
+
+    fn generate(&self, rng: &mut Rng, scale: usize) -> Option<Generated> {
+        Some(generate::generate(rng, scale))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kerned_beyond_u64() {
+        // The kerned record has 23 digits
+        let input = "Time:        829      847      901      616\n\
+                     Distance: 146688   176902   124830    94423\n";
+        let records = Day06.parse(input).expect("valid records");
+        assert_eq!(14668817690212483094423, records.kerned_distance);
+        assert_eq!(Answer::from(793707924275u64), Day06.part2(&records));
+    }
+
+    #[test]
+    fn test_race_without_time() {
+        let records = Day06
+            .parse("Time: 0\nDistance: 0\n")
+            .expect("valid records");
+        assert_eq!(Answer::from(0u64), Day06.part1(&records));
+        assert_eq!(Answer::from(0u64), Day06.part2(&records));
+        assert_eq!(0, part1::calculate_possible_ways_to_win(1, 0));
+        assert_eq!(4, part1::calculate_possible_ways_to_win(7, 9));
+    }
+}
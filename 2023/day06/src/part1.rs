@@ -1,48 +1,42 @@
-pub fn solve(input: &str) -> String {
-    // Parse numbers from each line (Time and Distance)
-    let numbers: Vec<Vec<u32>> = input
-        .lines()
-        .map(|line| {
-            let number_section = line.split(':').last().unwrap();
-            parse_numbers_separated_by_spaces(number_section)
-        })
-        .collect();
-
-    assert!(numbers.len() == 2, "Expected two lines with numbers");
-    let target_times = &numbers[0];
-    let distances = &numbers[1];
+use crate::RaceRecords;
+use aoc_core::math::isqrt;
+use aoc_core::Answer;
 
+pub fn solve(records: &RaceRecords) -> Answer {
     // Calculate the product of possible ways to win each competition
-    target_times
+    records
+        .times
         .iter()
-        .zip(distances)
-        .map(|(&time, &distance)| calculate_possible_ways_to_win(time, distance))
-        .product::<u32>()
-        .to_string()
-}
-
-fn calculate_possible_ways_to_win(time: u32, target_distance: u32) -> u32 {
-    (1..=time)
-        .filter(|&speed| speed * (time - speed) > target_distance)
-        .count() as u32
-}
-
-fn parse_numbers_separated_by_spaces(input: &str) -> Vec<u32> {
-    input
-        .split_whitespace()
-        .flat_map(str::parse::<u32>)
-        .collect()
+        .zip(&records.distances)
+        .map(|(&time, &distance)| calculate_possible_ways_to_win(time, distance.into()))
+        .map(u128::from)
+        .product::<u128>()
+        .into()
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_solve() {
-        let input = "Time:      7  15   30
-Distance:  9  40  200";
-        let solution = "288";
-        assert_eq!(solution, solve(input));
+// Holding the button for `speed` milliseconds wins when speed * (time - speed) > distance,
+// which holds strictly between the roots of speed² - time * speed + distance = 0
+pub(crate) fn calculate_possible_ways_to_win(time: u64, target_distance: u128) -> u64 {
+    let time = time as u128;
+    // Records beyond a quarter of time² cannot be beaten
+    let Some(discriminant) = target_distance
+        .checked_mul(4)
+        .and_then(|quadruple| (time * time).checked_sub(quadruple))
+    else {
+        return 0;
+    };
+    // Rounding the root down leaves the guess at most one below the first winning speed
+    let mut speed = (time - isqrt(discriminant)) / 2;
+    if speed * (time - speed) <= target_distance {
+        speed += 1;
+    }
+    // Races that take no time leave nothing to hold the button for
+    if speed > time {
+        return 0;
+    }
+    // The wins are symmetric around time / 2
+    match speed <= time - speed && speed * (time - speed) > target_distance {
+        true => (time - 2 * speed + 1) as u64,
+        false => 0,
     }
 }
@@ -0,0 +1,77 @@
+use crate::CARDS;
+use aoc_core::{Generated, Rng};
+use std::collections::HashSet;
+
+const HANDS: usize = 1000; // Official input size
+const JOKER_CARDS: &str = "J23456789TQKA"; // Card order when jacks are jokers
+
+// Unique hands, since equal hands with different bids would have no defined order
+pub fn generate(rng: &mut Rng, scale: usize) -> Generated {
+    let cards: Vec<char> = CARDS.chars().collect();
+    let mut hands: HashSet<String> = HashSet::new();
+    let mut plays: Vec<(String, u32)> = Vec::new();
+    let mut input = String::new();
+    while hands.len() < HANDS * scale {
+        // Drawing from fewer kinds of cards makes the stronger hand types as common
+        let mut kinds = cards.clone();
+        rng.shuffle(&mut kinds);
+        let kinds = &kinds[..rng.range(1..6)];
+        let hand: String = (0..5).map(|_| *rng.choose(kinds)).collect();
+        if hands.insert(hand.clone()) {
+            let bid = rng.range(1..1001);
+            input.push_str(&format!("{} {}\n", hand, bid));
+            plays.push((hand, bid));
+        }
+    }
+    Generated {
+        input,
+        answers: [
+            Some(winnings(&plays, false).into()),
+            Some(winnings(&plays, true).into()),
+        ],
+    }
+}
+
+fn winnings(plays: &[(String, u32)], jokers: bool) -> u64 {
+    let mut ranked: Vec<(Strength, u32)> = plays
+        .iter()
+        .map(|(hand, bid)| (strength(hand, jokers), *bid))
+        .collect();
+    ranked.sort();
+    ranked
+        .iter()
+        .zip(1..)
+        .map(|((_, bid), rank)| u64::from(*bid) * rank)
+        .sum()
+}
+
+// Amounts of each kind of card from the most common one, then the values of the cards.
+// Comparing the amounts orders the hand types from five of a kind down to high card.
+type Strength = (Vec<usize>, Vec<usize>);
+
+fn strength(hand: &str, jokers: bool) -> Strength {
+    let order = match jokers {
+        true => JOKER_CARDS,
+        false => CARDS,
+    };
+    let values = hand
+        .chars()
+        .map(|card| order.find(card).expect("hands are drawn from the cards"))
+        .collect();
+    let mut amounts: Vec<usize> = order
+        .chars()
+        .filter(|&kind| !(jokers && kind == 'J'))
+        .map(|kind| hand.matches(kind).count())
+        .filter(|&amount| amount > 0)
+        .collect();
+    amounts.sort_by(|a, b| b.cmp(a));
+    if jokers {
+        // Jokers join the most common kind
+        let jokers = hand.matches('J').count();
+        match amounts.first_mut() {
+            Some(amount) => *amount += jokers,
+            None => amounts.push(jokers),
+        }
+    }
+    (amounts, values)
+}
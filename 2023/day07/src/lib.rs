@@ -1,2 +1,57 @@
+mod generate;
 pub mod part1;
 pub mod part2;
+
+aoc_core::fixture_tests!();
+
+use aoc_core::{Answer, Generated, ParseError, Rng, Solution};
+
+pub struct Day07;
+
+pub struct Hand<'a> {
+    cards: &'a str,
+    bid: u32,
+}
+
+impl Solution for Day07 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 7;
+    type Input<'a> = Vec<Hand<'a>>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        input.lines().map(|line| parse_hand(input, line)).collect()
+    }
+
+    fn part1(&self, input: &Self::Input<'_>) -> Answer {
+        part1::solve(input)
+    }
+
+    fn part2(&self, input: &Self::Input<'_>) -> Answer {
+        part2::solve(input)
+    }
+
+    fn generate(&self, rng: &mut Rng, scale: usize) -> Option<Generated> {
+        Some(generate::generate(rng, scale))
+    }
+}
+
+const CARDS: &str = "23456789TJQKA";
+
+fn parse_hand<'a>(input: &str, line: &'a str) -> Result<Hand<'a>, ParseError> {
+    let error = |span: &str, message: &str| ParseError::new(Day07::DAY, input, span, message);
+    let (cards, bid) = line
+        .split_once(' ')
+        .ok_or_else(|| error(line, "space divides line parts"))?;
+    if cards.chars().count() != 5 || !cards.chars().all(|card| CARDS.contains(card)) {
+        return Err(error(
+            cards,
+            "hand should have five cards from 2-9, T, J, Q, K and A",
+        ));
+    }
+    Ok(Hand {
+        cards,
+        bid: bid
+            .parse()
+            .map_err(|_| error(bid, "bid should be a number"))?,
+    })
+}
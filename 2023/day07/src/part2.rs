@@ -1,3 +1,5 @@
+use crate::Hand;
+use aoc_core::Answer;
 use lazy_static::lazy_static;
 use std::cmp::Ordering;
 use std::collections::HashMap;
@@ -59,9 +61,9 @@ lazy_static! {
     };
 }
 
-pub fn solve(input: &str) -> String {
-    // Parse PokenPlays from the input
-    let mut plays: Vec<PokerPlay> = input.lines().map(parse_poker_play).collect();
+pub fn solve(hands: &[Hand]) -> Answer {
+    // Evaluate PokerPlays from the hands
+    let mut plays: Vec<PokerPlay> = hands.iter().map(parse_poker_play).collect();
 
     // Sort the plays from lowest to highest rank
     plays.sort();
@@ -70,22 +72,20 @@ pub fn solve(input: &str) -> String {
     plays
         .iter()
         .enumerate()
-        .fold(0, |acc, (index, play)| acc + play.bid * (index as u32 + 1))
-        .to_string()
+        .fold(0, |acc: u64, (index, play)| {
+            acc + u64::from(play.bid) * (index as u64 + 1)
+        })
+        .into()
 }
 
-fn parse_poker_play(line: &str) -> PokerPlay {
-    let parts = line.split_once(' ').expect("space divides line parts");
-    let cards = parts.0;
-    let card_values: Vec<u8> = cards.chars().map(get_card_value).collect();
-    let hand = get_poker_hand(&card_values);
-    let bid: u32 = parts.1.parse().expect("bid");
+fn parse_poker_play<'a>(hand: &Hand<'a>) -> PokerPlay<'a> {
+    let card_values: Vec<u8> = hand.cards.chars().map(get_card_value).collect();
 
     PokerPlay {
-        cards,
+        cards: hand.cards,
+        hand: get_poker_hand(&card_values),
         card_values,
-        hand,
-        bid,
+        bid: hand.bid,
     }
 }
 
@@ -160,19 +160,3 @@ fn mode_without_jokers(numbers: &[u8]) -> Option<u8> {
             *count
         })
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_solve() {
-        let input = "32T3K 765
-T55J5 684
-KK677 28
-KTJJT 220
-QQQJA 483";
-        let solution = "5905";
-        assert_eq!(solution, solve(input));
-    }
-}
@@ -0,0 +1,107 @@
+use aoc_core::math::lcm_all;
+use aoc_core::{Generated, Rng};
+
+const NODES: usize = 700; // Official input size
+const GHOSTS: usize = 6;
+const PRIMES: [u64; 21] = [
+    11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79, 83, 89, 97,
+];
+const LETTERS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+// Every ghost walks a loop of two rails of nodes, where both directions lead one step
+// further along the loop. The end node joins the rails, so the ghost reaches it every
+// `length` steps whatever the directions, and the loop lengths share a common factor like
+// the official inputs do.
+pub fn generate(rng: &mut Rng, scale: usize) -> Generated {
+    let mut primes = PRIMES.to_vec();
+    rng.shuffle(&mut primes);
+    let primes = &primes[..GHOSTS];
+    let mean_prime = primes.iter().sum::<u64>() / GHOSTS as u64;
+    let factor = ((NODES * scale / GHOSTS / 2) as u64 / mean_prime).max(1);
+    let lengths: Vec<u64> = primes.iter().map(|prime| prime * factor).collect();
+
+    let nodes = lengths.iter().map(|&length| 2 * length as usize).sum();
+    let mut names = NameGenerator::new(rng, nodes);
+    let mut lines: Vec<String> = Vec::with_capacity(nodes);
+    for (ghost, &length) in lengths.iter().enumerate() {
+        let (start, end) = match ghost {
+            0 => ("AAA".to_string(), "ZZZ".to_string()),
+            _ => names.start_and_end(),
+        };
+        // The rails have a node for every step between leaving the end node and returning
+        let rails: Vec<(String, String)> =
+            (1..length).map(|_| (names.next(), names.next())).collect();
+        let first = &rails[0];
+        lines.push(format!("{} = ({}, {})", start, first.0, first.1));
+        lines.push(format!("{} = ({}, {})", end, first.0, first.1));
+        for (index, (left, right)) in rails.iter().enumerate() {
+            let (next_left, next_right) = match rails.get(index + 1) {
+                Some(next) => (&next.0, &next.1),
+                None => (&end, &end),
+            };
+            lines.push(format!("{} = ({}, {})", left, next_left, next_right));
+            lines.push(format!("{} = ({}, {})", right, next_left, next_right));
+        }
+    }
+    rng.shuffle(&mut lines);
+
+    let directions: String = (0..rng.range(200..300))
+        .map(|_| *rng.choose(&['L', 'R']))
+        .collect();
+    let lcm = lcm_all(lengths.iter().copied()).expect("loop lengths are small");
+    Generated {
+        input: format!("{}\n\n{}\n", directions, lines.join("\n")),
+        answers: [Some(lengths[0].into()), Some(lcm.into())],
+    }
+}
+
+// Hands out unique labels, long enough to name every node
+struct NameGenerator {
+    names: Vec<String>,
+    prefixes: Vec<String>, // For start and end nodes, which only differ by their last letter
+}
+
+impl NameGenerator {
+    fn new(rng: &mut Rng, count: usize) -> Self {
+        // Labels of other nodes cannot end with 'A' or 'Z'
+        let mut length = 3;
+        while 24 * 26usize.pow(length as u32 - 1) < count {
+            length += 1;
+        }
+        let mut names: Vec<String> = (0..24 * 26usize.pow(length as u32 - 1))
+            .map(|index| {
+                label(index / 24, length - 1) + &(LETTERS[1 + index % 24] as char).to_string()
+            })
+            .collect();
+        rng.shuffle(&mut names);
+        names.truncate(count);
+
+        // "AA" would collide with the start of the first ghost, and "ZZ" with its end
+        let mut prefixes: Vec<String> = (0..26usize.pow(length as u32 - 1))
+            .map(|index| label(index, length - 1))
+            .filter(|prefix| prefix.bytes().any(|letter| letter != b'A'))
+            .filter(|prefix| prefix.bytes().any(|letter| letter != b'Z'))
+            .collect();
+        rng.shuffle(&mut prefixes);
+        NameGenerator { names, prefixes }
+    }
+
+    fn next(&mut self) -> String {
+        self.names.pop().expect("enough names for every node")
+    }
+
+    fn start_and_end(&mut self) -> (String, String) {
+        let prefix = self.prefixes.pop().expect("enough names for every ghost");
+        (format!("{}A", prefix), format!("{}Z", prefix))
+    }
+}
+
+// Letters of the number in base 26
+fn label(mut index: usize, length: usize) -> String {
+    let mut letters = vec![b'A'; length];
+    for letter in letters.iter_mut().rev() {
+        *letter = LETTERS[index % 26];
+        index /= 26;
+    }
+    String::from_utf8(letters).unwrap()
+}
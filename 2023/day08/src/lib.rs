@@ -1,2 +1,108 @@
+mod generate;
 pub mod part1;
 pub mod part2;
+
+aoc_core::fixture_tests!();
+
+use aoc_core::{Answer, Generated, ParseError, Rng, Solution};
+use std::collections::HashMap;
+
+pub struct Day08;
+
+pub struct Network<'a> {
+    directions: &'a str,
+    nodes: HashMap<&'a str, (&'a str, &'a str)>, // Node, (Left, Right)
+}
+
+// Node of a walk, and the index of the direction to take from it
+pub(crate) type Location<'a> = (&'a str, usize);
+
+impl<'a> Network<'a> {
+    // Location after following the next direction
+    pub(crate) fn step(&self, (node, index): Location<'a>) -> Location<'a> {
+        let options = self
+            .nodes
+            .get(node)
+            .expect("parsing checks that every node is defined");
+        let next_node = match self.directions.as_bytes()[index] {
+            b'L' => options.0,
+            b'R' => options.1,
+            _ => unreachable!(),
+        };
+        (next_node, (index + 1) % self.directions.len())
+    }
+}
+
+impl Solution for Day08 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 8;
+    type Input<'a> = Network<'a>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        let mut lines = input.lines();
+        let directions: &str = lines.next().unwrap_or_default();
+        if let Some(index) = directions.find(|c| c != 'L' && c != 'R') {
+            let message = "directions should only contain 'L' and 'R'";
+            return Err(ParseError::new(
+                Self::DAY,
+                input,
+                &directions[index..],
+                message,
+            ));
+        }
+        if directions.is_empty() {
+            let message = "expected directions on the first line";
+            return Err(ParseError::new(Self::DAY, input, directions, message));
+        }
+        lines.next(); // Skip over the empty line
+
+        let instructions: Vec<(&str, (&str, &str))> = lines
+            .map(|line| parse_instruction(input, line))
+            .collect::<Result<_, _>>()?;
+        let nodes: HashMap<&str, (&str, &str)> = instructions.iter().copied().collect();
+
+        // Walks would get stuck on a node that is not defined
+        let targets = instructions
+            .iter()
+            .flat_map(|(_, (left, right))| [left, right]);
+        if let Some(target) = targets
+            .into_iter()
+            .find(|target| !nodes.contains_key(*target))
+        {
+            let message = "every left and right node should be defined on a line of its own";
+            return Err(ParseError::new(Self::DAY, input, target, message));
+        }
+        Ok(Network { directions, nodes })
+    }
+
+    fn part1(&self, input: &Self::Input<'_>) -> Answer {
+        part1::solve(input)
+    }
+
+    fn part2(&self, input: &Self::Input<'_>) -> Answer {
+        part2::solve(input)
+    }
+
+    fn generate(&self, rng: &mut Rng, scale: usize) -> Option<Generated> {
+        Some(generate::generate(rng, scale))
+    }
+}
+
+// (Label, (Left, Right))
+fn parse_instruction<'a>(
+    input: &str,
+    line: &'a str,
+) -> Result<(&'a str, (&'a str, &'a str)), ParseError> {
+    let error = || ParseError::new(Day08::DAY, input, line, "expected 'AAA = (BBB, CCC)'");
+    let sides = line.split_once('=').ok_or_else(error)?;
+    let label = sides.0.trim();
+    let (left, right) = sides.1.split_once(',').ok_or_else(error)?;
+
+    //  " (ZZZ"
+    let left = left.split_once('(').ok_or_else(error)?.1;
+
+    //  " ZZZ)"
+    let right = right.trim().split_once(')').ok_or_else(error)?.0;
+
+    Ok((label, (left, right)))
+}
@@ -1,97 +1,115 @@
-use std::collections::HashMap;
+use crate::{Location, Network};
+use aoc_core::cycle::{find_cycle, Cycle};
+use aoc_core::math::{crt, isqrt};
+use aoc_core::Answer;
 
-pub fn solve(input: &str) -> String {
-    let mut lines = input.lines();
-    let directions: &str = lines.next().expect("pattern");
-    lines.next(); // Skip over the empty line
-
-    // Node, (Left, Right)
-    let node_instructions: HashMap<&str, (&str, &str)> = lines.map(parse_instruction).collect();
-
-    // Start simultaneously from all nodes that start with 'A'
-    let start_nodes: Vec<&str> = node_instructions
+pub fn solve(network: &Network) -> Answer {
+    // Start simultaneously from all nodes that end with 'A'
+    let walks: Vec<Walk> = network
+        .nodes
         .keys()
         .filter(|node| node.ends_with('A'))
-        .cloned()
+        .map(|node| Walk::new(node, network))
         .collect();
 
-    let steps_from_target_nodes: Vec<usize> = start_nodes
-        .iter()
-        .map(|node| steps_to_target_node(node, directions, &node_instructions))
-        .collect();
-
-    // Lowest common multiple of all the steps
-    lcm(&steps_from_target_nodes).to_string()
+    // The loops of the ghosts may never line up, and then there is no answer
+    match first_step_at_targets(&walks) {
+        Some(step) => step.into(),
+        None => Answer::Unsolved,
+    }
 }
 
-// (Label, (Left, Right))
-fn parse_instruction(line: &str) -> (&str, (&str, &str)) {
-    let sides = line.split_once('=').expect("sides of newline");
-    let label = sides.0.trim();
-    let (left, right) = sides.1.split_once(',').expect("left and right label");
-
-    //  " (ZZZ"
-    let left = left.split_once('(').expect("open paren").1;
-
-    //  " ZZZ)"
-    let right = right.trim().split_once(')').expect("close paren").0;
-
-    (label, (left, right))
+// Walk of one ghost, which repeats once the ghost is back at a node with the same
+// directions ahead of it
+struct Walk<'a> {
+    cycle: Cycle<Location<'a>>,
+    targets: Vec<usize>, // Steps before the repeat that end at a node ending with 'Z'
 }
 
-fn steps_to_target_node(node: &str, directions: &str, node_instructions: &HashMap<&str, (&str, &str)>) -> usize {
-    let mut current_node = node;
-    directions
-        .chars()
-        .cycle()
-        .enumerate()
-        .find_map(|(steps, direction)| {
-            let options = node_instructions.get(current_node).expect("");
-            let next_node = match direction {
-                'L' => &options.0,
-                'R' => &options.1,
-                _ => unreachable!(),
-            };
-            if next_node.ends_with('Z') {
-                Some(steps + 1)
-            } else {
-                current_node = next_node;
-                None
-            }
-        })
-        .expect("number of steps to the target node")
-}
+impl<'a> Walk<'a> {
+    fn new(start: &'a str, network: &Network<'a>) -> Self {
+        let cycle = find_cycle((start, 0), |&location| network.step(location));
+        let targets = (0..cycle.states().len())
+            .filter(|&step| cycle.state_at(step).0.ends_with('Z'))
+            .collect();
+        Walk { cycle, targets }
+    }
 
-fn lcm(nums: &[usize]) -> usize {
-    nums.iter()
-        .fold(nums[0], |lcm, &num| lcm * num / gcd(lcm, num))
-}
+    fn is_at_target(&self, step: usize) -> bool {
+        self.targets
+            .binary_search(&self.cycle.index_of(step))
+            .is_ok()
+    }
 
-fn gcd(a: usize, b: usize) -> usize {
-    if b == 0 {
-        a
-    } else {
-        gcd(b, a % b)
+    // Congruences, one of which the step satisfies whenever the ghost is at a target node
+    // within its loop, and their modulus. The loop is as long as the directions allow, so the
+    // targets often repeat within it and a shorter period gives fewer congruences.
+    fn loop_targets(&self) -> (Vec<u64>, u64) {
+        let (start, length) = (self.cycle.start, self.cycle.length);
+        let offsets: Vec<usize> = self
+            .targets
+            .iter()
+            .filter(|&&step| step >= start)
+            .map(|step| step - start)
+            .collect();
+        let repeats_after = |period: usize| {
+            offsets
+                .iter()
+                .all(|offset| offsets.binary_search(&((offset + period) % length)).is_ok())
+        };
+        let period = divisors(length)
+            .into_iter()
+            .find(|&period| repeats_after(period))
+            .expect("the targets repeat after the length of the loop");
+        let residues = offsets
+            .iter()
+            .take_while(|&&offset| offset < period)
+            .map(|offset| ((start + offset) % period) as u64)
+            .collect();
+        (residues, period as u64)
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+fn first_step_at_targets(walks: &[Walk]) -> Option<u64> {
+    // Until every ghost is within its loop, check the steps one by one
+    let loops_start = walks.iter().map(|walk| walk.cycle.start).max()?.max(1);
+    if let Some(step) =
+        (1..loops_start).find(|&step| walks.iter().all(|walk| walk.is_at_target(step)))
+    {
+        return Some(step as u64);
+    }
 
-    #[test]
-    fn test_solve() {
-        let input = "LR
+    // From there on, every ghost is at a target when the step satisfies one of its
+    // congruences, so combine one congruence of each ghost in every possible way
+    let mut combined: Vec<(u64, u64)> = vec![(0, 1)];
+    for (residues, period) in walks.iter().map(Walk::loop_targets) {
+        combined = combined
+            .iter()
+            .flat_map(|&congruence| {
+                residues
+                    .iter()
+                    .filter_map(move |&residue| crt(&[congruence, (residue, period)]))
+            })
+            .collect();
+    }
+    let loops_start = loops_start as u64;
+    combined
+        .into_iter()
+        .map(|(residue, modulus)| {
+            residue + loops_start.saturating_sub(residue).div_ceil(modulus) * modulus
+        })
+        .min()
+}
 
-11A = (11B, XXX)
-11B = (XXX, 11Z)
-11Z = (11B, XXX)
-22A = (22B, XXX)
-22B = (22C, 22C)
-22C = (22Z, 22Z)
-22Z = (22B, 22B)
-XXX = (XXX, XXX)";
-        let solution = "6";
-        assert_eq!(solution, solve(input));
+// Divisors in ascending order
+fn divisors(n: usize) -> Vec<usize> {
+    let (mut small, mut large): (Vec<usize>, Vec<usize>) = (Vec::new(), Vec::new());
+    for divisor in (1..=isqrt(n as u64) as usize).filter(|divisor| n.is_multiple_of(*divisor)) {
+        small.push(divisor);
+        if divisor != n / divisor {
+            large.push(n / divisor);
+        }
     }
+    small.extend(large.into_iter().rev());
+    small
 }
@@ -0,0 +1,39 @@
+use aoc_core::{Generated, Rng};
+
+const HISTORIES: usize = 200; // Official input size
+const LENGTH: usize = 21;
+
+// Histories are values of polynomials written with binomial coefficients,
+// f(x) = c0 + c1 * C(x, 1) + c2 * C(x, 2) + ..., which are whole numbers at every whole x.
+// The values just after and just before each history are then known.
+pub fn generate(rng: &mut Rng, scale: usize) -> Generated {
+    let mut input = String::new();
+    let (mut next, mut previous) = (0i64, 0i64);
+    for _ in 0..HISTORIES * scale {
+        // Differences reach zero before the history runs out
+        let coefficients: Vec<i64> = (0..rng.range(1..LENGTH - 1))
+            .map(|_| rng.range(-9..10))
+            .collect();
+        let value = |x: i64| -> i64 {
+            coefficients
+                .iter()
+                .enumerate()
+                .map(|(k, coefficient)| coefficient * binomial(x, k as i64))
+                .sum()
+        };
+        let history: Vec<String> = (0..LENGTH as i64).map(|x| value(x).to_string()).collect();
+        input.push_str(&history.join(" "));
+        input.push('\n');
+        next += value(LENGTH as i64);
+        previous += value(-1);
+    }
+    Generated {
+        input,
+        answers: [Some(next.into()), Some(previous.into())],
+    }
+}
+
+// C(x, k) for any whole x, which is (-1)^k for x = -1
+fn binomial(x: i64, k: i64) -> i64 {
+    (0..k).fold(1, |product, i| product * (x - i) / (i + 1))
+}
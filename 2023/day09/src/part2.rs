@@ -1,13 +1,15 @@
-pub fn solve(input: &str) -> String {
-    input
-        .lines()
-        .map(parse_numbers_separated_by_spaces_reversed)
-        .map(|mut numbers| calculate_extrapolated_number(&mut numbers))
+use aoc_core::Answer;
+
+pub fn solve(histories: &[Vec<i64>]) -> Answer {
+    histories
+        .iter()
+        .map(|numbers| reversed(numbers))
+        .map(|numbers| calculate_extrapolated_number(&numbers))
         .sum::<i64>()
-        .to_string()
+        .into()
 }
 
-fn calculate_extrapolated_number(numbers: &mut Vec<i64>) -> i64 {
+fn calculate_extrapolated_number(numbers: &[i64]) -> i64 {
     extrapolate_end_numbers(numbers, Vec::new()).iter().sum()
 }
 
@@ -29,25 +31,7 @@ fn extrapolate_end_numbers(numbers: &[i64], mut end_numbers: Vec<i64>) -> Vec<i6
     extrapolate_end_numbers(&differences, end_numbers)
 }
 
-fn parse_numbers_separated_by_spaces_reversed(input: &str) -> Vec<i64> {
-    // Parse numbers from input line from right to left
-    input
-        .split_whitespace()
-        .flat_map(str::parse::<i64>)
-        .rev()
-        .collect()
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_solve() {
-        let input = "0 3 6 9 12 15
-1 3 6 10 15 21
-10 13 16 21 30 45";
-        let solution = "2";
-        assert_eq!(solution, solve(input));
-    }
+fn reversed(numbers: &[i64]) -> Vec<i64> {
+    // Extrapolating the numbers from right to left gives the previous number
+    numbers.iter().rev().copied().collect()
 }
@@ -0,0 +1,21 @@
+use crate::{find_loop, render, Tile};
+use aoc_core::grid::Position;
+use aoc_core::{Animation, Grid, Overlay, Part, Picture};
+
+// Walks the loop from the Start tile one tile at a time ("step"), and in part 2 shows the
+// tiles enclosed by the finished loop ("enclosed")
+pub fn animate(grid: &Grid<Tile>, part: Part, animation: &mut Animation) {
+    let path: Vec<Position> = find_loop(grid);
+    for length in 1..=path.len() {
+        animation.step("step", || {
+            Picture::new(grid, render::tile_color).with(Overlay::Path {
+                cells: path[..length].to_vec(),
+                closed: false,
+                color: render::LOOP,
+            })
+        });
+    }
+    if part == Part::Two {
+        animation.step("enclosed", || render::render(grid, part));
+    }
+}
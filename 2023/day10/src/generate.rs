@@ -0,0 +1,155 @@
+use aoc_core::random::scaled_side;
+use aoc_core::{Generated, Grid, Rng};
+
+const SIZE: usize = 140; // Official input is a square of this size
+const JUNK_PIPES: &[char] = &['|', '-', 'L', 'J', '7', 'F'];
+
+// Connections of a pipe
+const NORTH: u8 = 1;
+const EAST: u8 = 2;
+const SOUTH: u8 = 4;
+const WEST: u8 = 8;
+
+// The loop is the outline of a random tree of cells, which has no holes and no cells touching
+// only by their corners, so the outline is a single closed loop. Junk pipes fill the rest.
+//
+// The outline runs along the corners of the cells, and drawing it at double resolution puts
+// a pipe on every corner and on the middle of every side. A tree of `cells` with `sides`
+// outline sides then has a loop of `2 * sides` pipes enclosing, by Pick's theorem,
+// `4 * cells - sides + 1` tiles.
+pub fn generate(rng: &mut Rng, scale: usize) -> Generated {
+    let nodes = (scaled_side(SIZE, scale) + 1) / 4;
+    let cells = random_tree(rng, nodes.max(2));
+
+    let mut connections = Grid::filled(2 * cells.height() + 1, 2 * cells.width() + 1, 0u8);
+    let mut sides = 0;
+    for ((row, col), _) in cells.iter().filter(|(_, &cell)| cell) {
+        let outside = |rows: isize, cols: isize| {
+            let neighbor = (row.checked_add_signed(rows), col.checked_add_signed(cols));
+            match neighbor {
+                (Some(row), Some(col)) => !cells.get((row, col)).copied().unwrap_or(false),
+                _ => true,
+            }
+        };
+        // Corners of the cell at double resolution
+        let (top, left, bottom, right) = (2 * row, 2 * col, 2 * row + 2, 2 * col + 2);
+        let outline = [
+            (
+                outside(-1, 0),
+                (top, left),
+                EAST,
+                (top, left + 1),
+                (top, right),
+                WEST,
+            ),
+            (
+                outside(1, 0),
+                (bottom, left),
+                EAST,
+                (bottom, left + 1),
+                (bottom, right),
+                WEST,
+            ),
+            (
+                outside(0, -1),
+                (top, left),
+                SOUTH,
+                (top + 1, left),
+                (bottom, left),
+                NORTH,
+            ),
+            (
+                outside(0, 1),
+                (top, right),
+                SOUTH,
+                (top + 1, right),
+                (bottom, right),
+                NORTH,
+            ),
+        ];
+        for (is_outline, from, towards, middle, to, back) in outline {
+            if is_outline {
+                sides += 1;
+                connections[from] |= towards;
+                connections[middle] |= towards | back;
+                connections[to] |= back;
+            }
+        }
+    }
+
+    let mut tiles = connections.map(|&pipe| match pipe {
+        0 => None,
+        _ => Some(pipe_tile(pipe)),
+    });
+    let starts: Vec<(usize, usize)> = connections.positions(|&pipe| pipe & SOUTH != 0).collect();
+    // The solution leaves the start going south
+    let start = *rng.choose(&starts);
+    tiles[start] = Some('S');
+
+    let junk = tiles.map(|tile| match tile {
+        Some(tile) => *tile,
+        None if rng.chance(0.6) => *rng.choose(JUNK_PIPES),
+        None => '.',
+    });
+    let tree_cells = cells.iter().filter(|(_, &cell)| cell).count();
+    Generated {
+        input: format!("{}\n", junk),
+        answers: [
+            Some(sides.into()),
+            Some((4 * tree_cells - sides + 1).into()),
+        ],
+    }
+}
+
+// Cells of a random spanning tree grown over part of a grid of nodes. Nodes are every other
+// cell and the edges between them fill the cell in between, so separate branches never touch.
+fn random_tree(rng: &mut Rng, nodes: usize) -> Grid<bool> {
+    let mut cells = Grid::filled(2 * nodes - 1, 2 * nodes - 1, false);
+    let target = nodes * nodes * rng.range(40..80) / 100;
+    let root = (2 * rng.range(0..nodes), 2 * rng.range(0..nodes));
+    cells[root] = true;
+    let mut frontier: Vec<Edge> = Vec::new();
+    push_edges(&cells, &mut frontier, root);
+
+    let mut grown = 1;
+    while grown < target && !frontier.is_empty() {
+        let (from, to) = frontier.swap_remove(rng.range(0..frontier.len()));
+        if cells[to] {
+            continue;
+        }
+        cells[to] = true;
+        cells[((from.0 + to.0) / 2, (from.1 + to.1) / 2)] = true;
+        grown += 1;
+        push_edges(&cells, &mut frontier, to);
+    }
+    cells
+}
+
+// (from, to) nodes
+type Edge = ((usize, usize), (usize, usize));
+
+// Adds the edges from the node to its neighbouring nodes that are not in the tree yet
+fn push_edges(cells: &Grid<bool>, frontier: &mut Vec<Edge>, (row, col): (usize, usize)) {
+    for (rows, cols) in [(-2, 0), (2, 0), (0, -2), (0, 2)] {
+        let (Some(next_row), Some(next_col)) =
+            (row.checked_add_signed(rows), col.checked_add_signed(cols))
+        else {
+            continue;
+        };
+        if cells.get((next_row, next_col)) == Some(&false) {
+            frontier.push(((row, col), (next_row, next_col)));
+        }
+    }
+}
+
+fn pipe_tile(pipe: u8) -> char {
+    match pipe {
+        _ if pipe == NORTH | SOUTH => '|',
+        _ if pipe == EAST | WEST => '-',
+        _ if pipe == NORTH | EAST => 'L',
+        _ if pipe == NORTH | WEST => 'J',
+        _ if pipe == SOUTH | WEST => '7',
+        _ if pipe == SOUTH | EAST => 'F',
+        _ => unreachable!("outline corners join exactly two sides"),
+    }
+}
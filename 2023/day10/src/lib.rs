@@ -1,2 +1,146 @@
+mod animate;
+mod generate;
 pub mod part1;
 pub mod part2;
+mod render;
+
+aoc_core::fixture_tests!();
+
+use aoc_core::graph::cycle_through;
+use aoc_core::grid::Position;
+use aoc_core::{
+    Animation, Answer, Direction4, Generated, Grid, ParseError, Part, Picture, Rng, Solution,
+};
+
+pub struct Day10;
+
+#[derive(PartialEq)]
+pub enum Tile {
+    VerticalPipe,
+    HorizontalPipe,
+    BendNE,
+    BendNW,
+    BendSE,
+    BendSW,
+    Ground,
+    Start,
+}
+
+impl Tile {
+    // Directions that the pipe of the tile leads in. The Start tile can lead anywhere.
+    fn connections(&self) -> &'static [Direction4] {
+        use Direction4::{East, North, South, West};
+        match self {
+            Tile::VerticalPipe => &[North, South],
+            Tile::HorizontalPipe => &[East, West],
+            Tile::BendNE => &[North, East],
+            Tile::BendNW => &[North, West],
+            Tile::BendSE => &[South, East],
+            Tile::BendSW => &[South, West],
+            Tile::Ground => &[],
+            Tile::Start => &Direction4::ALL,
+        }
+    }
+}
+
+// Tiles that the pipe of the tile connects to, when their pipes lead back to it
+pub(crate) fn connected_tiles(grid: &Grid<Tile>, position: Position) -> Vec<Position> {
+    grid[position]
+        .connections()
+        .iter()
+        .filter_map(|&direction| {
+            let next = grid.step(position, direction)?;
+            let back = direction.reverse();
+            grid[next].connections().contains(&back).then_some(next)
+        })
+        .collect()
+}
+
+// Tiles of the loop through the Start tile, starting from it
+pub(crate) fn find_loop(grid: &Grid<Tile>) -> Vec<Position> {
+    let start = grid
+        .position(|tile| *tile == Tile::Start)
+        .expect("parsing checks that the grid contains a Start tile");
+    cycle_through(start, |&position| connected_tiles(grid, position))
+        .expect("parsing checks that the Start tile is on a loop")
+}
+
+impl Solution for Day10 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 10;
+    type Input<'a> = Grid<Tile>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        let grid = Grid::from_chars(input.trim_end(), parse_tile)
+            .map_err(|err| err.into_parse_error(Self::DAY, input))?;
+
+        // The loop is walked from the Start tile
+        let starts: Vec<Position> = grid.positions(|tile| *tile == Tile::Start).collect();
+        let [start] = starts[..] else {
+            let message = "input should contain exactly one Start tile 'S'";
+            return Err(ParseError::new(Self::DAY, input, input, message));
+        };
+        if cycle_through(start, |&position| connected_tiles(&grid, position)).is_none() {
+            let line = input.lines().nth(start.0).unwrap_or(input);
+            let message = "Start tile 'S' should be on a loop of pipes";
+            return Err(ParseError::new(Self::DAY, input, line, message));
+        }
+        Ok(grid)
+    }
+
+    fn part1(&self, input: &Self::Input<'_>) -> Answer {
+        part1::solve(input)
+    }
+
+    fn part2(&self, input: &Self::Input<'_>) -> Answer {
+        part2::solve(input)
+    }
+
+    fn generate(&self, rng: &mut Rng, scale: usize) -> Option<Generated> {
+        Some(generate::generate(rng, scale))
+    }
+
+    fn render(&self, input: &Self::Input<'_>, part: Part) -> Option<Picture> {
+        Some(render::render(input, part))
+    }
+
+    fn animate(&self, input: &Self::Input<'_>, part: Part, animation: &mut Animation) -> bool {
+        animate::animate(input, part, animation);
+        true
+    }
+}
+
+fn parse_tile(c: char) -> Result<Tile, &'static str> {
+    match c {
+        '|' => Ok(Tile::VerticalPipe),
+        '-' => Ok(Tile::HorizontalPipe),
+        'L' => Ok(Tile::BendNE),
+        'J' => Ok(Tile::BendNW),
+        'F' => Ok(Tile::BendSE),
+        '7' => Ok(Tile::BendSW),
+        '.' => Ok(Tile::Ground),
+        'S' => Ok(Tile::Start),
+        _ => Err("unknown tile character"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_unknown_tile() {
+        let input = "-L|F7\n7S-7|\nL|7x|";
+        let error = Day10.parse(input).err().expect("parse error");
+        assert_eq!(
+            (3, 4, "L|7x|"),
+            (error.line, error.column, error.snippet.as_str())
+        );
+    }
+
+    #[test]
+    fn test_parse_start_without_loop() {
+        let error = Day10.parse("S.\n..").err().expect("parse error");
+        assert_eq!((1, "S."), (error.line, error.snippet.as_str()));
+    }
+}
@@ -0,0 +1,31 @@
+use crate::{find_loop, part2, Tile};
+use aoc_core::grid::Position;
+use aoc_core::{Grid, Overlay, Part, Picture, Rgb};
+
+pub(crate) const LOOP: Rgb = Rgb(255, 200, 40);
+const ENCLOSED: Rgb = Rgb(60, 180, 90);
+
+// Pipes with the loop drawn over them, and in part 2 the tiles enclosed by the loop
+pub fn render(grid: &Grid<Tile>, part: Part) -> Picture {
+    let path: Vec<Position> = find_loop(grid);
+    let mut picture = Picture::new(grid, tile_color);
+    if part == Part::Two {
+        picture = picture.with(Overlay::Cells {
+            cells: part2::find_tiles_within_loop(grid, &path),
+            color: ENCLOSED,
+        });
+    }
+    picture.with(Overlay::Path {
+        cells: path,
+        closed: true,
+        color: LOOP,
+    })
+}
+
+pub(crate) fn tile_color(tile: &Tile) -> Rgb {
+    match tile {
+        Tile::Ground => Rgb(20, 20, 30),
+        Tile::Start => Rgb(230, 50, 50),
+        _ => Rgb(90, 90, 110), // Pipes that are not on the loop stay visible under it
+    }
+}
@@ -0,0 +1,45 @@
+use aoc_core::random::scaled_side;
+use aoc_core::{Generated, Grid, Rng};
+
+const SIZE: usize = 140; // Official input is a square of this size
+
+// Sparse galaxies, with a few rows and columns left empty so that space expands
+pub fn generate(rng: &mut Rng, scale: usize) -> Generated {
+    let size = scaled_side(SIZE, scale);
+    let empty_lines =
+        |rng: &mut Rng| -> Vec<bool> { (0..size).map(|_| rng.chance(0.06)).collect() };
+    let empty_rows = empty_lines(rng);
+    let empty_columns = empty_lines(rng);
+    let image = Grid::from_fn(size, size, |(row, col)| {
+        match !empty_rows[row] && !empty_columns[col] && rng.chance(0.025) {
+            true => '#',
+            false => '.',
+        }
+    });
+
+    // Distances along the rows and the columns add up separately
+    let galaxies: Vec<(usize, usize)> = image.positions(|&cell| cell == '#').collect();
+    let rows: Vec<usize> = galaxies.iter().map(|&(row, _)| row).collect();
+    let cols: Vec<usize> = galaxies.iter().map(|&(_, col)| col).collect();
+    let distances = |expansion: u64| distances(&rows, expansion) + distances(&cols, expansion);
+    Generated {
+        input: format!("{}\n", image),
+        answers: [Some(distances(2).into()), Some(distances(1_000_000).into())],
+    }
+}
+
+// Sum of the distances between every pair of the coordinates, once lines without any of
+// them (which may also happen by chance) have grown to `expansion` lines
+fn distances(coordinates: &[usize], expansion: u64) -> u64 {
+    let mut sorted = coordinates.to_vec();
+    sorted.sort();
+    let (mut total, mut sum_before, mut position) = (0, 0, 0);
+    for (before, pair) in (1..).zip(sorted.windows(2)) {
+        // The coordinate is the farther one of a pair with every coordinate before it
+        sum_before += position;
+        let gap = (pair[1] - pair[0]) as u64;
+        position += gap + gap.saturating_sub(1) * (expansion - 1);
+        total += position * before - sum_before;
+    }
+    total
+}
@@ -1,2 +1,52 @@
+mod generate;
 pub mod part1;
 pub mod part2;
+mod render;
+
+aoc_core::fixture_tests!();
+
+use aoc_core::{Answer, Generated, Grid, ParseError, Part, Picture, Rng, Solution};
+
+pub struct Day11;
+
+#[derive(Clone, PartialEq)]
+pub enum Tile {
+    Empty,
+    Expanded, // Empty tile marked as expanded space, never parsed from the input
+    Galaxy,
+}
+
+impl Solution for Day11 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 11;
+    type Input<'a> = Grid<Tile>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        Grid::from_chars(input.trim_end(), parse_tile)
+            .map_err(|err| err.into_parse_error(Self::DAY, input))
+    }
+
+    fn part1(&self, input: &Self::Input<'_>) -> Answer {
+        part1::solve(input)
+    }
+
+    fn part2(&self, input: &Self::Input<'_>) -> Answer {
+        part2::solve(input)
+    }
+
+    fn generate(&self, rng: &mut Rng, scale: usize) -> Option<Generated> {
+        Some(generate::generate(rng, scale))
+    }
+
+    fn render(&self, input: &Self::Input<'_>, part: Part) -> Option<Picture> {
+        Some(render::render(input, part))
+    }
+}
+
+fn parse_tile(c: char) -> Result<Tile, &'static str> {
+    match c {
+        '.' => Ok(Tile::Empty),
+        '#' => Ok(Tile::Galaxy),
+        _ => Err("input should only contain galaxy tiles and empty tiles"),
+    }
+}
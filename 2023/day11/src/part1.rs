@@ -1,7 +1,9 @@
-pub fn solve(input: &str) -> String {
-    let matrix: Vec<Vec<Tile>> = input.lines().map(parse_tiles).collect();
-    let expanded_matrix: Vec<Vec<Tile>> = expand_empty_rows_and_columns(&matrix);
-    let galaxy_coordinates: Vec<Coordinate> = find_galaxy_coordinates(&expanded_matrix);
+use crate::Tile;
+use aoc_core::{Answer, Grid, Point};
+
+pub fn solve(grid: &Grid<Tile>) -> Answer {
+    let expanded_grid: Grid<Tile> = expand_empty_rows_and_columns(grid);
+    let galaxy_coordinates: Vec<Point> = find_galaxy_coordinates(&expanded_grid);
 
     // Calculate distances between the pairs of galaxies
     galaxy_coordinates
@@ -10,129 +12,66 @@ pub fn solve(input: &str) -> String {
         .map(|(index, coordinate)| {
             find_distances_to_pairs(&galaxy_coordinates, coordinate, index)
                 .iter()
-                .sum::<u32>()
+                .sum::<u64>()
         })
-        .sum::<u32>()
-        .to_string()
-}
-
-#[derive(Clone, PartialEq)]
-enum Tile {
-    Empty,
-    Galaxy,
-}
-
-#[derive(PartialEq)]
-struct Coordinate {
-    x: usize,
-    y: usize,
+        .sum::<u64>()
+        .into()
 }
 
 fn find_distances_to_pairs(
-    galaxy_coordinates: &[Coordinate],
-    source: &Coordinate,
+    galaxy_coordinates: &[Point],
+    source: &Point,
     galaxy_index: usize,
-) -> Vec<u32> {
+) -> Vec<u64> {
     galaxy_coordinates
         .iter()
         .skip(galaxy_index + 1) // The distance to previous galaxies are already calculated
-        .map(|target| (source.x.abs_diff(target.x) + source.y.abs_diff(target.y)) as u32)
-        .collect::<Vec<u32>>()
+        .map(|target| source.manhattan_distance(*target))
+        .collect::<Vec<u64>>()
 }
 
-fn expand_empty_rows_and_columns(matrix: &[Vec<Tile>]) -> Vec<Vec<Tile>> {
-    let empty_rows: Vec<usize> = find_empty_rows(matrix);
-    let empty_columns: Vec<usize> = find_empty_columns(matrix);
-    expand_matrix(matrix, empty_rows, empty_columns)
+pub(crate) fn expand_empty_rows_and_columns(grid: &Grid<Tile>) -> Grid<Tile> {
+    let empty_rows: Vec<usize> = find_empty_rows(grid);
+    let empty_columns: Vec<usize> = find_empty_columns(grid);
+    expand_grid(grid, empty_rows, empty_columns)
 }
 
-fn expand_matrix(
-    matrix: &[Vec<Tile>],
-    empty_rows: Vec<usize>,
-    empty_columns: Vec<usize>,
-) -> Vec<Vec<Tile>> {
-    // Expand empty rows to two
-    let expanded_matrix = empty_rows
-        .into_iter()
-        .rev()
-        .fold(matrix.to_owned(), |mut acc, row| {
-            acc.insert(row, matrix[row].clone());
-            acc
-        });
+fn expand_grid(grid: &Grid<Tile>, empty_rows: Vec<usize>, empty_columns: Vec<usize>) -> Grid<Tile> {
+    // Empty rows and columns are expanded to two
+    let expand = |lines: Vec<usize>, empty_lines: Vec<usize>| -> Vec<usize> {
+        lines
+            .into_iter()
+            .flat_map(|line| vec![line; if empty_lines.contains(&line) { 2 } else { 1 }])
+            .collect()
+    };
+    let rows: Vec<usize> = expand((0..grid.height()).collect(), empty_rows);
+    let columns: Vec<usize> = expand((0..grid.width()).collect(), empty_columns);
 
-    // Expand empty columns to two
-    expanded_matrix
-        .into_iter()
-        .map(|mut row| {
-            empty_columns.iter().rev().for_each(|col| {
-                row.insert(*col, Tile::Empty);
-            });
-            row
-        })
-        .collect()
+    // Copy each tile from its original row and column
+    Grid::from_fn(rows.len(), columns.len(), |(y, x)| {
+        grid[(rows[y], columns[x])].clone()
+    })
 }
 
-fn find_empty_rows(matrix: &[Vec<Tile>]) -> Vec<usize> {
-    matrix
-        .iter()
+fn find_empty_rows(grid: &Grid<Tile>) -> Vec<usize> {
+    grid.rows()
         .enumerate()
         .filter_map(|(y, row)| if galaxy_in_tiles(row) { None } else { Some(y) })
         .collect()
 }
 
-fn find_empty_columns(matrix: &[Vec<Tile>]) -> Vec<usize> {
-    (0..matrix[0].len())
-        .filter(|&x| matrix.iter().all(|row| row[x] == Tile::Empty))
+fn find_empty_columns(grid: &Grid<Tile>) -> Vec<usize> {
+    (0..grid.width())
+        .filter(|&x| grid.column(x).all(|tile| *tile == Tile::Empty))
         .collect()
 }
 
 fn galaxy_in_tiles(tiles: &[Tile]) -> bool {
-    tiles.iter().any(|tile| *tile == Tile::Galaxy)
-}
-
-fn find_galaxy_coordinates(matrix: &[Vec<Tile>]) -> Vec<Coordinate> {
-    matrix
-        .iter()
-        .enumerate()
-        .flat_map(|(y, row)| {
-            row.iter()
-                .enumerate()
-                .filter_map(move |(x, tile)| match tile {
-                    Tile::Empty => None,
-                    Tile::Galaxy => Some(Coordinate { x, y }),
-                })
-        })
-        .collect()
+    tiles.contains(&Tile::Galaxy)
 }
 
-fn parse_tiles(input: &str) -> Vec<Tile> {
-    input
-        .chars()
-        .map(|c| match c {
-            '.' => Tile::Empty,
-            '#' => Tile::Galaxy,
-            _ => unreachable!("input should only contain galaxy tiles and empty tiles"),
-        })
+fn find_galaxy_coordinates(grid: &Grid<Tile>) -> Vec<Point> {
+    grid.positions(|tile| *tile == Tile::Galaxy)
+        .map(Point::from)
         .collect()
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_solve() {
-        let input = "...#......
-.......#..
-#.........
-..........
-......#...
-.#........
-.........#
-..........
-.......#..
-#...#.....";
-        let solution = "374";
-        assert_eq!(solution, solve(input));
-    }
-}
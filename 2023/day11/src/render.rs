@@ -0,0 +1,20 @@
+use crate::{part1, part2, Tile};
+use aoc_core::{Grid, Part, Picture, Rgb};
+
+// Galaxies after expansion. Part 1 doubles the empty rows and columns, while part 2 marks
+// them as expanded, since a million copies would not fit in a picture.
+pub fn render(grid: &Grid<Tile>, part: Part) -> Picture {
+    let expanded_grid: Grid<Tile> = match part {
+        Part::One => part1::expand_empty_rows_and_columns(grid),
+        Part::Two => part2::expand_empty_rows_and_columns(grid),
+    };
+    Picture::new(&expanded_grid, tile_color)
+}
+
+fn tile_color(tile: &Tile) -> Rgb {
+    match tile {
+        Tile::Empty => Rgb(10, 10, 40),
+        Tile::Expanded => Rgb(50, 40, 110),
+        Tile::Galaxy => Rgb(255, 240, 170),
+    }
+}
@@ -0,0 +1,107 @@
+use aoc_core::{Generated, Rng};
+
+const RECORDS: usize = 1000; // Official input size
+const COPIES: usize = 5; // Part 2 unfolds every record into five copies
+
+// Records made by hiding some springs of a random row, with their arrangements counted
+// separately from the solution
+pub fn generate(rng: &mut Rng, scale: usize) -> Generated {
+    let mut input = String::new();
+    let (mut part1, mut part2) = (0u64, 0u64);
+    for _ in 0..RECORDS * scale {
+        let mut row: Vec<u8> = (0..rng.range(4..21))
+            .map(|_| match rng.chance(0.45) {
+                true => b'#',
+                false => b'.',
+            })
+            .collect();
+        // Records always have damaged springs
+        let damaged = rng.range(0..row.len());
+        row[damaged] = b'#';
+        let groups: Vec<usize> = row
+            .split(|&spring| spring == b'.')
+            .map(|group| group.len())
+            .filter(|&length| length > 0)
+            .collect();
+        let springs: Vec<u8> = row
+            .iter()
+            .map(|&spring| match rng.chance(0.6) {
+                true => b'?',
+                false => spring,
+            })
+            .collect();
+
+        let unfolded_springs = [springs.as_slice(); COPIES].join(&b'?');
+        part1 += arrangements(&springs, &groups);
+        part2 += arrangements(&unfolded_springs, &groups.repeat(COPIES));
+        let sizes: Vec<String> = groups.iter().map(|size| size.to_string()).collect();
+        input.push_str(&format!(
+            "{} {}\n",
+            String::from_utf8(springs).unwrap(),
+            sizes.join(",")
+        ));
+    }
+    Generated {
+        input,
+        answers: [Some(part1.into()), Some(part2.into())],
+    }
+}
+
+// Ways to place the groups of damaged springs, counted from the end of the record so that
+// `ways[index][group]` covers the springs from `index` and the groups from `group` on
+fn arrangements(springs: &[u8], groups: &[usize]) -> u64 {
+    let mut ways = vec![vec![0u64; groups.len() + 1]; springs.len() + 2];
+    ways[springs.len()][groups.len()] = 1;
+    ways[springs.len() + 1][groups.len()] = 1; // Past the spring that ends the last group
+    for index in (0..springs.len()).rev() {
+        for group in 0..=groups.len() {
+            if springs[index] != b'#' {
+                ways[index][group] += ways[index + 1][group];
+            }
+            let Some(&size) = groups.get(group) else {
+                continue;
+            };
+            let end = index + size;
+            let fits = springs[index] != b'.'
+                && end <= springs.len()
+                && !springs[index..end].contains(&b'.')
+                && springs.get(end) != Some(&b'#');
+            if fits {
+                ways[index][group] += ways[end + 1][group + 1];
+            }
+        }
+    }
+    ways[0][0]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arrangements() {
+        let example = [
+            ("???.###", vec![1, 1, 3], 1),
+            (".??..??...?##.", vec![1, 1, 3], 4),
+            ("?#?#?#?#?#?#?#?", vec![1, 3, 1, 6], 1),
+            ("????.#...#...", vec![4, 1, 1], 1),
+            ("????.######..#####.", vec![1, 6, 5], 4),
+            ("?###????????", vec![3, 2, 1], 10),
+        ];
+        let (mut folded, mut unfolded) = (0, 0);
+        for (springs, groups, expected) in example {
+            assert_eq!(
+                expected,
+                arrangements(springs.as_bytes(), &groups),
+                "{}",
+                springs
+            );
+            folded += arrangements(springs.as_bytes(), &groups);
+            let springs = [springs; COPIES].join("?");
+            unfolded += arrangements(springs.as_bytes(), &groups.repeat(COPIES));
+        }
+        assert_eq!((21, 525152), (folded, unfolded));
+        assert_eq!(0, arrangements(b"#.#", &[3]));
+        assert_eq!(1, arrangements(b"", &[]));
+    }
+}
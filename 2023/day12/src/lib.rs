@@ -1,2 +1,104 @@
+mod generate;
 pub mod part1;
-pub mod part2;
+
+aoc_core::fixture_tests!();
+
+use aoc_core::{Answer, Generated, ParseError, Rng, Solution};
+use std::fmt::{self, Write};
+
+pub struct Day12;
+
+#[derive(Debug)]
+pub struct ConditionRecord {
+    springs: Vec<Spring>,
+    group_sizes: Vec<usize>,
+}
+
+#[derive(Debug)]
+pub enum Spring {
+    Damaged,
+    Operational,
+    Unknown,
+}
+
+// Same notation as the puzzle input, like ???.### 1,1,3
+impl fmt::Display for ConditionRecord {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for spring in &self.springs {
+            let symbol = match spring {
+                Spring::Damaged => '#',
+                Spring::Operational => '.',
+                Spring::Unknown => '?',
+            };
+            f.write_char(symbol)?;
+        }
+        let sizes: Vec<String> = self.group_sizes.iter().map(usize::to_string).collect();
+        write!(f, " {}", sizes.join(","))
+    }
+}
+
+impl Solution for Day12 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 12;
+    type Input<'a> = Vec<ConditionRecord>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        input
+            .lines()
+            .map(|line| parse_condition_record(input, line))
+            .collect()
+    }
+
+    fn part1(&self, input: &Self::Input<'_>) -> Answer {
+        part1::solve(input)
+    }
+
+    fn part2(&self, _input: &Self::Input<'_>) -> Answer {
+        Answer::Unsolved
+    }
+
+    fn generate(&self, rng: &mut Rng, scale: usize) -> Option<Generated> {
+        Some(generate::generate(rng, scale))
+    }
+}
+
+fn parse_condition_record(input: &str, line: &str) -> Result<ConditionRecord, ParseError> {
+    let (springs_str, group_sizes_str) = line.split_once(' ').ok_or_else(|| {
+        let message = "Condition record parts are separated by a space";
+        ParseError::new(Day12::DAY, input, line, message)
+    })?;
+
+    let springs = parse_springs(input, springs_str)?;
+    let group_sizes = parse_group_sizes(input, group_sizes_str)?;
+    Ok(ConditionRecord {
+        springs,
+        group_sizes,
+    })
+}
+
+fn parse_springs(input: &str, springs: &str) -> Result<Vec<Spring>, ParseError> {
+    springs
+        .char_indices()
+        .map(|(index, character)| match character {
+            '#' => Ok(Spring::Damaged),
+            '.' => Ok(Spring::Operational),
+            '?' => Ok(Spring::Unknown),
+            _ => {
+                let span = &springs[index..index + character.len_utf8()];
+                let message = "The puzzle input consists of '#', '.', and '?' characters";
+                Err(ParseError::new(Day12::DAY, input, span, message))
+            }
+        })
+        .collect()
+}
+
+fn parse_group_sizes(input: &str, group_sizes: &str) -> Result<Vec<usize>, ParseError> {
+    group_sizes
+        .split(',')
+        .map(|size| {
+            size.parse().map_err(|_| {
+                ParseError::new(Day12::DAY, input, size, "group size should be a number")
+            })
+        })
+        .collect()
+}
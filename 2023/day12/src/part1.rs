@@ -1,73 +1,31 @@
-#[derive(Debug)]
-struct ConditionRecord {
-    springs: Vec<Spring>,
-    group_sizes: Vec<usize>,
-}
-
-#[derive(Debug)]
-enum Spring {
-    Damaged,
-    Operational,
-    Unknown,
-}
-
-pub fn solve(input: &str) -> String {
-    let spring_rows: Vec<ConditionRecord> = parse_lines(input);
-    dbg!(spring_rows);
-
-    "TODO".to_string()
-}
-
-fn parse_lines(input: &str) -> Vec<ConditionRecord> {
-    input.lines().map(parse_condition_record).collect()
-}
-
-fn parse_condition_record(line: &str) -> ConditionRecord {
-    let (springs_str, group_sizes_str) = line
-        .split_once(' ')
-        .expect("Condition record parts are separated by a space");
-
-    let springs = parse_springs(springs_str);
-    let group_sizes = parse_group_sizes(group_sizes_str);
-    ConditionRecord {
-        springs,
-        group_sizes,
+use crate::ConditionRecord;
+use aoc_core::trace::{self, Event, Level};
+use aoc_core::Answer;
+use std::fmt;
+
+pub fn solve(spring_rows: &[ConditionRecord]) -> Answer {
+    for (index, record) in spring_rows.iter().enumerate() {
+        trace::emit(RowParsed {
+            row: index + 1,
+            record,
+        });
     }
-}
 
-fn parse_springs(input: &str) -> Vec<Spring> {
-    input.chars().map(char_to_spring).collect()
+    Answer::Unsolved
 }
 
-fn parse_group_sizes(input: &str) -> Vec<usize> {
-    input
-        .split(',')
-        .map(|c| c.parse().expect("group size"))
-        .collect()
+// row 1: ???.### 1,1,3
+struct RowParsed<'a> {
+    row: usize,
+    record: &'a ConditionRecord,
 }
 
-fn char_to_spring(character: char) -> Spring {
-    match character {
-        '#' => Spring::Damaged,
-        '.' => Spring::Operational,
-        '?' => Spring::Unknown,
-        _ => unreachable!("The puzzle input consists of '#', '.', and '?' characters"),
+impl fmt::Display for RowParsed<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "row {}: {}", self.row, self.record)
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_solve() {
-        let input = "???.### 1,1,3
-.??..??...?##. 1,1,3
-?#?#?#?#?#?#?#? 1,3,1,6
-????.#...#... 4,1,1
-????.######..#####. 1,6,5
-?###???????? 3,2,1";
-        let solution = "21";
-        assert_eq!(solution, solve(input));
-    }
+impl Event for RowParsed<'_> {
+    const LEVEL: Level = Level::Debug;
 }
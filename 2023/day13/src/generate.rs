@@ -0,0 +1,99 @@
+use aoc_core::{Generated, Grid, Rng};
+
+const PATTERNS: usize = 100; // Official input size
+
+// Patterns mirrored over a row line and a column line at once. Toggling one cell on a row
+// that the row line does not reflect keeps the row reflection perfect and leaves the column
+// line off by exactly that cell, which becomes the smudge of part 2.
+pub fn generate(rng: &mut Rng, scale: usize) -> Generated {
+    let mut patterns: Vec<String> = Vec::with_capacity(PATTERNS * scale);
+    let (mut part1, mut part2) = (0, 0);
+    while patterns.len() < PATTERNS * scale {
+        let pattern = mirrored_pattern(rng);
+        // Random patterns may reflect over other lines too, which makes the answer ambiguous
+        let (Some(perfect), Some(smudged)) =
+            (line_with_errors(&pattern, 0), line_with_errors(&pattern, 1))
+        else {
+            continue;
+        };
+        part1 += perfect;
+        part2 += smudged;
+        patterns.push(pattern.to_string());
+    }
+    Generated {
+        input: format!("{}\n", patterns.join("\n\n")),
+        answers: [Some(part1.into()), Some(part2.into())],
+    }
+}
+
+fn mirrored_pattern(rng: &mut Rng) -> Grid<char> {
+    let (height, width) = (rng.range(5..18), rng.range(5..18));
+    // The row line leaves some rows unreflected for the smudge
+    let row_line = loop {
+        let line = rng.range(1..height);
+        if 2 * line != height {
+            break line;
+        }
+    };
+    let col_line = rng.range(1..width);
+    let cells = Grid::from_fn(height, width, |_| rng.chance(0.5));
+    let mut pattern = Grid::from_fn(height, width, |(row, col)| {
+        cells[(fold(row, row_line, height), fold(col, col_line, width))]
+    });
+
+    let row_span = row_line.min(height - row_line);
+    let col_span = col_line.min(width - col_line);
+    let free_rows: Vec<usize> = (0..height)
+        .filter(|&row| row + row_span < row_line || row >= row_line + row_span)
+        .collect();
+    let smudge = (
+        *rng.choose(&free_rows),
+        rng.range(col_line - col_span..col_line + col_span),
+    );
+    pattern[smudge] = !pattern[smudge];
+
+    let pattern = match rng.chance(0.5) {
+        true => pattern.transpose(),
+        false => pattern,
+    };
+    pattern.map(|&rock| match rock {
+        true => '#',
+        false => '.',
+    })
+}
+
+// Index of the cell whose mirror image the cell is, over the line before `line`
+fn fold(index: usize, line: usize, length: usize) -> usize {
+    let span = line.min(length - line);
+    match index >= line && index < line + span {
+        true => 2 * line - 1 - index,
+        false => index,
+    }
+}
+
+// Summary value of the only reflection line with this many differing cells
+fn line_with_errors(pattern: &Grid<char>, errors: usize) -> Option<usize> {
+    let rows = lines_with_errors(pattern, errors).map(|row| 100 * row);
+    let transposed = pattern.transpose();
+    let cols = lines_with_errors(&transposed, errors);
+    let mut lines = rows.chain(cols);
+    match (lines.next(), lines.next()) {
+        (Some(line), None) => Some(line),
+        _ => None,
+    }
+}
+
+// Row lines, counted by the rows above them, whose reflection has this many differences
+fn lines_with_errors(pattern: &Grid<char>, errors: usize) -> impl Iterator<Item = usize> + '_ {
+    (1..pattern.height()).filter(move |&line| {
+        let span = line.min(pattern.height() - line);
+        let differences: usize = (0..span)
+            .map(|offset| {
+                let above = pattern.row(line - 1 - offset);
+                let below = pattern.row(line + offset);
+                above.iter().zip(below).filter(|(a, b)| a != b).count()
+            })
+            .sum();
+        differences == errors
+    })
+}
@@ -1,2 +1,69 @@
+mod generate;
 pub mod part1;
 pub mod part2;
+mod render;
+
+aoc_core::fixture_tests!();
+
+use aoc_core::{Answer, Generated, Grid, ParseError, Part, Picture, Rng, Solution};
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 13;
+    type Input<'a> = Vec<Grid<char>>; // Patterns of ash and rocks
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        let patterns: Vec<&str> = input.trim_end().split("\n\n").collect();
+        patterns
+            .iter()
+            .map(|pattern| parse_pattern(input, pattern))
+            .collect()
+    }
+
+    fn part1(&self, input: &Self::Input<'_>) -> Answer {
+        part1::solve(input)
+    }
+
+    fn part2(&self, input: &Self::Input<'_>) -> Answer {
+        part2::solve(input)
+    }
+
+    fn generate(&self, rng: &mut Rng, scale: usize) -> Option<Generated> {
+        Some(generate::generate(rng, scale))
+    }
+
+    fn render(&self, input: &Self::Input<'_>, part: Part) -> Option<Picture> {
+        Some(render::render(input, part))
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum ReflectionLine {
+    Horizontal(usize), // row
+    Vertical(usize),   // column
+}
+
+fn parse_pattern(input: &str, pattern: &str) -> Result<Grid<char>, ParseError> {
+    if pattern.trim().is_empty() {
+        return Err(ParseError::new(Day13::DAY, input, pattern, "empty pattern"));
+    }
+    Grid::from_chars(pattern, |c| match c {
+        '#' | '.' => Ok(c),
+        _ => Err("The puzzle input consists of '#' and '.' characters"),
+    })
+    .map_err(|err| err.into_parse_error(Day13::DAY, input))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pattern_without_reflection() {
+        let patterns = Day13.parse("#").expect("valid pattern");
+        assert_eq!(Answer::Unsolved, Day13.part1(&patterns));
+        assert_eq!(Answer::Unsolved, Day13.part2(&patterns));
+    }
+}
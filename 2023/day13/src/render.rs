@@ -0,0 +1,63 @@
+use crate::{part1, part2, ReflectionLine};
+use aoc_core::{Grid, Overlay, Part, Picture, Rgb};
+
+const MIRROR: Rgb = Rgb(230, 50, 50);
+
+// Patterns laid out side by side in rows, each with its reflection line drawn over it
+pub fn render(patterns: &[Grid<char>], part: Part) -> Picture {
+    // Every pattern gets a slot of the same size, with a gap of one tile to the next one
+    let slot_height = patterns.iter().map(Grid::height).max().unwrap_or(0) + 1;
+    let slot_width = patterns.iter().map(Grid::width).max().unwrap_or(0) + 1;
+    let columns = (patterns.len() as f64).sqrt().ceil().max(1.0) as usize;
+    let rows = patterns.len().div_ceil(columns);
+    let corner = |index: usize| {
+        (
+            (index / columns) * slot_height,
+            (index % columns) * slot_width,
+        )
+    };
+
+    let sheet = Grid::from_fn(rows * slot_height, columns * slot_width, |(y, x)| {
+        let index = (y / slot_height) * columns + x / slot_width;
+        let (top, left) = corner(index);
+        patterns
+            .get(index)
+            .and_then(|pattern| pattern.get((y - top, x - left)))
+            .copied()
+    });
+    let mut picture = Picture::new(&sheet, tile_color);
+
+    for (index, pattern) in patterns.iter().enumerate() {
+        // Patterns without a reflection line are drawn without one
+        let line = match part {
+            Part::One => part1::find_reflection_line(pattern),
+            Part::Two => part2::find_reflection_line_from_altered_pattern(pattern),
+        };
+        let Some(line) = line else {
+            continue;
+        };
+        let (top, left) = corner(index);
+        let (from, to) = match line {
+            ReflectionLine::Horizontal(row) => {
+                ((top + row, left), (top + row, left + pattern.width()))
+            }
+            ReflectionLine::Vertical(col) => {
+                ((top, left + col), (top + pattern.height(), left + col))
+            }
+        };
+        picture = picture.with(Overlay::Line {
+            from,
+            to,
+            color: MIRROR,
+        });
+    }
+    picture
+}
+
+fn tile_color(tile: &Option<char>) -> Rgb {
+    match tile {
+        Some('#') => Rgb(200, 200, 210), // Rock
+        Some(_) => Rgb(70, 70, 80),      // Ash
+        None => Rgb(0, 0, 0),            // Gap between patterns
+    }
+}
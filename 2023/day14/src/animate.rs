@@ -0,0 +1,27 @@
+use crate::{part2, render, Tile};
+use aoc_core::cycle::find_cycle;
+use aoc_core::{Animation, Direction4, Grid, Part, Picture};
+
+// Part 1 tilts north once. Part 2 spins until the platform repeats, with a frame after every
+// tilt ("tilt") and after every full spin cycle ("cycle").
+pub fn animate(grid: &Grid<Tile>, part: Part, animation: &mut Animation) {
+    let picture = |grid: &Grid<Tile>| Picture::new(grid, render::tile_color);
+    let mut grid: Grid<Tile> = grid.clone();
+    animation.step("start", || picture(&grid));
+    if part == Part::One {
+        part2::tilt(&mut grid, Direction4::North);
+        animation.step("tilt", || picture(&grid));
+        return;
+    }
+
+    // Spinning stops once the platform is back in a state it has been in
+    find_cycle(grid, |grid| {
+        let mut grid: Grid<Tile> = grid.clone();
+        for direction in part2::SPIN_CYCLE {
+            part2::tilt(&mut grid, direction);
+            animation.step("tilt", || picture(&grid));
+        }
+        animation.step("cycle", || picture(&grid));
+        grid
+    });
+}
@@ -0,0 +1,37 @@
+use aoc_core::random::scaled_side;
+use aoc_core::{Generated, Grid, Rng};
+
+const SIZE: usize = 100; // Official input is a square of this size
+
+// Platform of round rocks and cube-shaped rocks scattered at about the official density.
+// The load after tilting north follows from where each rock stops. The load after the spin
+// cycles is left out, since the only way to know it is to spin the platform until it
+// repeats, which is the whole solution and as slow as it.
+pub fn generate(rng: &mut Rng, scale: usize) -> Generated {
+    let size = scaled_side(SIZE, scale);
+    let platform = Grid::from_fn(size, size, |_| match rng.range(0..100) {
+        0..=14 => '#',
+        15..=34 => 'O',
+        _ => '.',
+    });
+
+    // Round rocks roll north onto the rock before them, or the row below the cube before them
+    let mut load = 0;
+    for col in 0..size {
+        let mut free_row = 0;
+        for row in 0..size {
+            match platform[(row, col)] {
+                '#' => free_row = row + 1,
+                'O' => {
+                    load += size - free_row;
+                    free_row += 1;
+                }
+                _ => {}
+            }
+        }
+    }
+    Generated {
+        input: format!("{}\n", platform),
+        answers: [Some(load.into()), None],
+    }
+}
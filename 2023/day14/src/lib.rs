@@ -1,2 +1,59 @@
+mod animate;
+mod generate;
 pub mod part1;
 pub mod part2;
+mod render;
+
+aoc_core::fixture_tests!();
+
+use aoc_core::{Animation, Answer, Generated, Grid, ParseError, Part, Picture, Rng, Solution};
+
+pub struct Day14;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Tile {
+    Empty,
+    CubeRock,
+    RoundRock,
+}
+
+impl Solution for Day14 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 14;
+    type Input<'a> = Grid<Tile>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        Grid::from_chars(input.trim_end(), parse_tile)
+            .map_err(|err| err.into_parse_error(Self::DAY, input))
+    }
+
+    fn part1(&self, input: &Self::Input<'_>) -> Answer {
+        part1::solve(input)
+    }
+
+    fn part2(&self, input: &Self::Input<'_>) -> Answer {
+        part2::solve(input)
+    }
+
+    fn generate(&self, rng: &mut Rng, scale: usize) -> Option<Generated> {
+        Some(generate::generate(rng, scale))
+    }
+
+    fn render(&self, input: &Self::Input<'_>, part: Part) -> Option<Picture> {
+        Some(render::render(input, part))
+    }
+
+    fn animate(&self, input: &Self::Input<'_>, part: Part, animation: &mut Animation) -> bool {
+        animate::animate(input, part, animation);
+        true
+    }
+}
+
+fn parse_tile(c: char) -> Result<Tile, &'static str> {
+    match c {
+        '.' => Ok(Tile::Empty),
+        '#' => Ok(Tile::CubeRock),
+        'O' => Ok(Tile::RoundRock),
+        _ => Err("The puzzle input only contains '.', '#', and 'O' characters"),
+    }
+}
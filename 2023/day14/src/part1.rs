@@ -1,64 +1,25 @@
-pub fn solve(input: &str) -> String {
-    let matrix: TileMatrix = input.lines().map(parse_tiles_from_line).collect();
-    calculate_load_after_tilt(&matrix).to_string()
-}
-
-type TileMatrix = Vec<Vec<Tile>>;
-
-#[derive(Debug, PartialEq)]
-enum Tile {
-    Empty,
-    CubeRock,
-    RoundRock,
-}
+use crate::Tile;
+use aoc_core::{Answer, Grid};
 
-fn parse_tiles_from_line(line: &str) -> Vec<Tile> {
-    line.chars()
-        .map(|c| match c {
-            '.' => Tile::Empty,
-            '#' => Tile::CubeRock,
-            'O' => Tile::RoundRock,
-            _ => unreachable!("The puzzle input only contains '.', '#', and 'O' characters"),
-        })
-        .collect()
+pub fn solve(grid: &Grid<Tile>) -> Answer {
+    calculate_load_after_tilt(grid).into()
 }
 
-fn calculate_load_after_tilt(matrix: &TileMatrix) -> usize {
-    let matrix_height: usize = matrix[0].len();
+fn calculate_load_after_tilt(grid: &Grid<Tile>) -> usize {
+    let grid_height: usize = grid.height();
     let mut total_load = 0;
-    for x in 0..matrix_height {
-        let mut rock_weight = matrix_height;
-        for (y, row) in matrix.iter().enumerate() {
-            match row[x] {
+    for x in 0..grid.width() {
+        let mut rock_weight = grid_height;
+        for (y, tile) in grid.column(x).enumerate() {
+            match tile {
                 Tile::RoundRock => {
                     total_load += rock_weight;
                     rock_weight -= 1;
                 }
-                Tile::CubeRock => rock_weight = matrix_height - y - 1,
+                Tile::CubeRock => rock_weight = grid_height - y - 1,
                 Tile::Empty => {}
             }
         }
     }
     total_load
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_solve() {
-        let input = "O....#....
-O.OO#....#
-.....##...
-OO.#O....O
-.O.....O#.
-O.#..O.#.#
-..O..#O..O
-.......O..
-#....###..
-#OO..#....";
-        let solution = "136";
-        assert_eq!(solution, solve(input));
-    }
-}
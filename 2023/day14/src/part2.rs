@@ -1,209 +1,140 @@
-const CYCLES: usize = 1_000_000_000;
-
-pub fn solve(input: &str) -> String {
-    let mut matrix: TileMatrix = input.lines().map(parse_tiles_from_line).collect();
-
-    // Cycling the matrix enough times should result in a loop
-    // Find the loop and gather information for it to calculate what matrix would be the `CYCLES`th
-    let matrices: Vec<TileMatrix> = cycle_matrix_until_loop_is_found(&mut matrix, CYCLES);
-    let (loop_index, loop_length) = get_loop_info(matrices.clone());
-
-    // Calculate the load for the matrix that would be the `CYCLES`th if we continued cycling
-    let final_index: usize = (CYCLES - loop_index) % loop_length + loop_index;
-    let final_matrix: &TileMatrix = &matrices[final_index];
-    calculate_load(final_matrix).to_string()
-}
-
-type TileMatrix = Vec<Vec<Tile>>;
-type Coordinate = (usize, usize);
-
-#[derive(Debug, Clone, PartialEq)]
-enum Tile {
-    Empty,
-    CubeRock,
-    RoundRock,
-}
-
-enum Direction {
-    North,
-    South,
-    East,
-    West,
-}
+use crate::Tile;
+use aoc_core::cycle::find_cycle;
+use aoc_core::grid::Position;
+use aoc_core::{Answer, Direction4, Grid};
 
-fn parse_tiles_from_line(line: &str) -> Vec<Tile> {
-    line.chars()
-        .map(|c| match c {
-            '.' => Tile::Empty,
-            '#' => Tile::CubeRock,
-            'O' => Tile::RoundRock,
-            _ => unreachable!("The puzzle input only contains '.', '#', and 'O' characters"),
-        })
-        .collect()
+const CYCLES: usize = 1_000_000_000;
+// Directions that a spin cycle tilts the platform in
+pub(crate) const SPIN_CYCLE: [Direction4; 4] = [
+    Direction4::North,
+    Direction4::West,
+    Direction4::South,
+    Direction4::East,
+];
+
+pub fn solve(grid: &Grid<Tile>) -> Answer {
+    calculate_load(&find_final_grid(grid)).into()
 }
 
-fn get_loop_info(matrices: Vec<TileMatrix>) -> (usize, usize) {
-    // The last matrix in `matrices` is the first matrix contained twice
-    // We can derive the loop index and length by finding its pair
-    let last_matrix = matrices.last().unwrap();
-    let matrices_len = matrices.len();
-
-    for (i, matrix) in matrices.iter().enumerate().take(matrices_len - 1) {
-        if matrix == last_matrix {
-            return (i, matrices_len - i - 1);
-        }
+pub(crate) fn find_final_grid(grid: &Grid<Tile>) -> Grid<Tile> {
+    // Cycling the rocks enough times should result in a loop, which tells where they would
+    // be after the `CYCLES`th cycle if we continued cycling
+    let tilts: Vec<Tilt> = SPIN_CYCLE
+        .iter()
+        .map(|&direction| Tilt::new(grid, direction))
+        .collect();
+    let rocks: Rocks = (0..)
+        .zip(grid.iter())
+        .filter(|(_, (_, tile))| **tile == Tile::RoundRock)
+        .map(|(index, _)| index)
+        .collect();
+    let spun = |rocks: &Rocks| {
+        tilts
+            .iter()
+            .fold(rocks.clone(), |rocks, tilt| tilt.apply(&rocks))
+    };
+    let rocks: Rocks = find_cycle(rocks, spun).into_state_at(CYCLES);
+
+    // Put the rocks back onto the platform
+    let mut final_grid: Grid<Tile> = grid.map(|tile| match tile {
+        Tile::RoundRock => Tile::Empty,
+        _ => tile.clone(),
+    });
+    for index in rocks {
+        let index = index as usize;
+        final_grid[(index / grid.width(), index % grid.width())] = Tile::RoundRock;
     }
-    unreachable!();
+    final_grid
 }
 
-fn cycle_matrix_until_loop_is_found(matrix: &mut TileMatrix, cycles: usize) -> Vec<TileMatrix> {
-    let mut matrices: Vec<TileMatrix> = vec![matrix.clone()];
-    for _ in 0..cycles {
-        tilt_lever(matrix, Direction::North);
-        tilt_lever(matrix, Direction::West);
-        tilt_lever(matrix, Direction::South);
-        tilt_lever(matrix, Direction::East);
-        if matrices.contains(matrix) {
-            matrices.push(matrix.clone());
-            return matrices;
-        }
-        matrices.push(matrix.clone());
-    }
-    unreachable!("Puzzle input should result in a loop");
-}
+// Indices of the round rocks in the grid, counted row by row. Only the round rocks move, so
+// they are all that changes between spin cycles.
+type Rocks = Vec<u32>;
 
-fn tilt_lever(matrix: &mut TileMatrix, direction: Direction) {
-    for (y, row) in matrix.clone().iter().enumerate() {
-        for (x, tile) in row.iter().enumerate() {
-            if *tile == Tile::RoundRock {
-                match direction {
-                    Direction::North => slide_north(matrix, (x, y)),
-                    Direction::South => slide_south(matrix, (x, y)),
-                    Direction::East => slide_east(matrix, (x, y)),
-                    Direction::West => slide_west(matrix, (x, y)),
-                }
-            }
-        }
-    }
+// Where the round rocks stop when the platform is tilted in one direction. Cube rocks never
+// move, so the stretches of tiles between them are found once for the whole search.
+struct Tilt {
+    stretch_of: Vec<u32>,     // Stretch of every tile, by its index
+    stretches: Vec<Vec<u32>>, // Tiles of every stretch, from the end the rocks roll towards
 }
 
-fn slide_north(matrix: &mut TileMatrix, coordinate: Coordinate) {
-    let x = coordinate.0;
-    let mut target_coordinate = coordinate;
-    let mut round_rocks: Vec<Coordinate> = Vec::new();
-    for y in (0..coordinate.1).rev() {
-        match matrix[y][x] {
-            Tile::Empty => target_coordinate = (x, y),
-            Tile::CubeRock => {
-                target_coordinate = (x, y + 1);
-                while coordinate != target_coordinate && round_rocks.contains(&target_coordinate) {
-                    target_coordinate.1 += 1
+impl Tilt {
+    fn new(grid: &Grid<Tile>, direction: Direction4) -> Self {
+        let index = |(row, col): Position| (row * grid.width() + col) as u32;
+        let mut stretch_of: Vec<u32> = vec![0; grid.height() * grid.width()];
+        let mut stretches: Vec<Vec<u32>> = Vec::new();
+        for start in edge(grid, direction) {
+            // A cube rock ends the stretch, and the tile after it starts the next one
+            let mut stretch: Option<usize> = None;
+            for position in grid.walk(start, direction.reverse()) {
+                if grid[position] == Tile::CubeRock {
+                    stretch = None;
+                    continue;
                 }
-                break;
+                let stretch = *stretch.get_or_insert_with(|| {
+                    stretches.push(Vec::new());
+                    stretches.len() - 1
+                });
+                stretch_of[index(position) as usize] = stretch as u32;
+                stretches[stretch].push(index(position));
             }
-            Tile::RoundRock => round_rocks.push((x, y)),
         }
-    }
-    move_tile(matrix, coordinate, target_coordinate)
-}
-
-fn slide_south(matrix: &mut TileMatrix, coordinate: Coordinate) {
-    let x = coordinate.0;
-    let matrix_height = matrix.len();
-    let mut round_rocks: Vec<Coordinate> = Vec::new();
-    let mut target_coordinate = coordinate;
-    for y in coordinate.1..matrix_height {
-        match matrix[y][x] {
-            Tile::Empty => target_coordinate = (x, y),
-            Tile::CubeRock => {
-                target_coordinate = (x, y - 1);
-                while coordinate != target_coordinate && round_rocks.contains(&target_coordinate) {
-                    target_coordinate.1 -= 1
-                }
-                break;
-            }
-            Tile::RoundRock => round_rocks.push((x, y)),
+        Tilt {
+            stretch_of,
+            stretches,
         }
     }
-    move_tile(matrix, coordinate, target_coordinate)
-}
 
-fn slide_west(matrix: &mut TileMatrix, coordinate: Coordinate) {
-    let y = coordinate.1;
-    let mut target_coordinate = coordinate;
-    let mut round_rocks: Vec<Coordinate> = Vec::new();
-    for x in (0..coordinate.0).rev() {
-        match matrix[y][x] {
-            Tile::Empty => target_coordinate = (x, y),
-            Tile::CubeRock => {
-                target_coordinate = (x + 1, y);
-                while coordinate != target_coordinate && round_rocks.contains(&target_coordinate) {
-                    target_coordinate.0 += 1
-                }
-                break;
-            }
-            Tile::RoundRock => round_rocks.push((x, y)),
+    fn apply(&self, rocks: &Rocks) -> Rocks {
+        // Rocks pile up from the end of their stretch
+        let mut counts: Vec<usize> = vec![0; self.stretches.len()];
+        for &rock in rocks {
+            counts[self.stretch_of[rock as usize] as usize] += 1;
         }
+        self.stretches
+            .iter()
+            .zip(counts)
+            .flat_map(|(stretch, count)| &stretch[..count])
+            .copied()
+            .collect()
     }
-    move_tile(matrix, coordinate, target_coordinate)
 }
 
-fn slide_east(matrix: &mut TileMatrix, coordinate: Coordinate) {
-    let y = coordinate.1;
-    let matrix_width = matrix[0].len();
-    let mut round_rocks: Vec<Coordinate> = Vec::new();
-    let mut target_coordinate = coordinate;
-    for x in coordinate.0..matrix_width {
-        match matrix[y][x] {
-            Tile::Empty => target_coordinate = (x, y),
-            Tile::CubeRock => {
-                target_coordinate = (x - 1, y);
-                while coordinate != target_coordinate && round_rocks.contains(&target_coordinate) {
-                    target_coordinate.0 -= 1;
-                }
-                break;
-            }
-            Tile::RoundRock => round_rocks.push((x, y)),
-        }
+// Tiles on the edge that the rocks roll towards
+fn edge(grid: &Grid<Tile>, direction: Direction4) -> Vec<Position> {
+    let (height, width) = (grid.height(), grid.width());
+    match direction {
+        Direction4::North => (0..width).map(|x| (0, x)).collect(),
+        Direction4::South => (0..width).map(|x| (height - 1, x)).collect(),
+        Direction4::West => (0..height).map(|y| (y, 0)).collect(),
+        Direction4::East => (0..height).map(|y| (y, width - 1)).collect(),
     }
-    move_tile(matrix, coordinate, target_coordinate)
 }
 
-fn move_tile(matrix: &mut TileMatrix, source: Coordinate, target: Coordinate) {
-    matrix[source.1][source.0] = Tile::Empty;
-    matrix[target.1][target.0] = Tile::RoundRock;
-}
-
-fn calculate_load(matrix: &TileMatrix) -> usize {
-    let matrix_height: usize = matrix[0].len();
-    let mut total_load = 0;
-    for (y, row) in matrix.iter().enumerate() {
-        for tile in row.iter() {
-            if tile == &Tile::RoundRock {
-                total_load += matrix_height - y
+pub(crate) fn tilt(grid: &mut Grid<Tile>, direction: Direction4) {
+    // Every line of tiles is walked from the edge that the rocks roll towards
+    let back = direction.reverse();
+    for start in edge(grid, direction) {
+        // Round rocks roll up to the first free tile after a cube rock or the edge
+        let (mut free, mut next) = (start, Some(start));
+        while let Some(position) = next {
+            next = grid.step(position, back);
+            match grid[position] {
+                Tile::RoundRock => {
+                    grid[position] = Tile::Empty;
+                    grid[free] = Tile::RoundRock;
+                    free = grid.step(free, back).unwrap_or(free);
+                }
+                Tile::CubeRock => free = next.unwrap_or(position),
+                Tile::Empty => {}
             }
         }
     }
-    total_load
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_solve() {
-        let input = "O....#....
-O.OO#....#
-.....##...
-OO.#O....O
-.O.....O#.
-O.#..O.#.#
-..O..#O..O
-.......O..
-#....###..
-#OO..#....";
-        let solution = "64";
-        assert_eq!(solution, solve(input));
-    }
+fn calculate_load(grid: &Grid<Tile>) -> usize {
+    let grid_height: usize = grid.height();
+    grid.positions(|tile| *tile == Tile::RoundRock)
+        .map(|(y, _)| grid_height - y)
+        .sum()
 }
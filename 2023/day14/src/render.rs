@@ -0,0 +1,23 @@
+use crate::{part2, Tile};
+use aoc_core::{Direction4, Grid, Part, Picture, Rgb};
+
+// Rocks after tilting north in part 1, and after all the spin cycles in part 2
+pub fn render(grid: &Grid<Tile>, part: Part) -> Picture {
+    let tilted_grid: Grid<Tile> = match part {
+        Part::One => {
+            let mut grid: Grid<Tile> = grid.clone();
+            part2::tilt(&mut grid, Direction4::North);
+            grid
+        }
+        Part::Two => part2::find_final_grid(grid),
+    };
+    Picture::new(&tilted_grid, tile_color)
+}
+
+pub(crate) fn tile_color(tile: &Tile) -> Rgb {
+    match tile {
+        Tile::Empty => Rgb(25, 25, 30),
+        Tile::CubeRock => Rgb(110, 110, 120),
+        Tile::RoundRock => Rgb(240, 150, 40),
+    }
+}
@@ -0,0 +1,63 @@
+use aoc_core::{Generated, Rng};
+use std::collections::HashMap;
+
+const STEPS: usize = 4000; // Official input size
+const LABELS: usize = 500;
+
+// Steps on a limited set of labels, so lenses are replaced and removed as well as inserted
+pub fn generate(rng: &mut Rng, scale: usize) -> Generated {
+    let labels: Vec<String> = (0..LABELS * scale)
+        .map(|_| {
+            (0..rng.range(2..7))
+                .map(|_| rng.range(b'a'..b'z' + 1) as char)
+                .collect()
+        })
+        .collect();
+
+    // A lens keeps its place in the box when it is replaced, so the lenses of a box are in
+    // the order of the steps that put them there
+    let mut lenses: HashMap<&str, (usize, usize)> = HashMap::new(); // Label to step and focal length
+    let mut steps: Vec<String> = Vec::with_capacity(STEPS * scale);
+    for step in 0..STEPS * scale {
+        let label = rng.choose(&labels);
+        match rng.chance(0.4) {
+            true => {
+                lenses.remove(label.as_str());
+                steps.push(format!("{}-", label));
+            }
+            false => {
+                let focal_length = rng.range(1..10);
+                let lens = lenses.entry(label).or_insert((step, focal_length));
+                lens.1 = focal_length;
+                steps.push(format!("{}={}", label, focal_length));
+            }
+        }
+    }
+
+    let mut boxes: Vec<Vec<(usize, usize)>> = vec![Vec::new(); 256];
+    for (label, lens) in lenses {
+        boxes[hash(label)].push(lens);
+    }
+    let focusing_power: usize = boxes
+        .iter_mut()
+        .zip(1..)
+        .map(|(lenses, box_number)| {
+            lenses.sort();
+            let slots = lenses.iter().zip(1..);
+            let power: usize = slots
+                .map(|(&(_, focal_length), slot)| slot * focal_length)
+                .sum();
+            box_number * power
+        })
+        .sum();
+    let hashes: usize = steps.iter().map(|step| hash(step)).sum();
+    Generated {
+        input: format!("{}\n", steps.join(",")),
+        answers: [Some(hashes.into()), Some(focusing_power.into())],
+    }
+}
+
+fn hash(text: &str) -> usize {
+    text.bytes()
+        .fold(0, |value, byte| (value + byte as usize) * 17 % 256)
+}
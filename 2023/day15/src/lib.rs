@@ -1,2 +1,70 @@
+mod generate;
 pub mod part1;
 pub mod part2;
+
+aoc_core::fixture_tests!();
+
+use aoc_core::{Answer, Generated, ParseError, Rng, Solution};
+
+pub struct Day15;
+
+pub struct Step<'a> {
+    text: &'a str, // The whole step is hashed in part 1
+    label: &'a str,
+    operation: Operation,
+}
+
+pub enum Operation {
+    Remove,
+    Insert(usize), // Focal length
+}
+
+impl Solution for Day15 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 15;
+    type Input<'a> = Vec<Step<'a>>; // Initialization sequence steps
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        input
+            .trim_end()
+            .split(',')
+            .map(|step| parse_step(input, step))
+            .collect()
+    }
+
+    fn part1(&self, input: &Self::Input<'_>) -> Answer {
+        part1::solve(input)
+    }
+
+    fn part2(&self, input: &Self::Input<'_>) -> Answer {
+        part2::solve(input)
+    }
+
+    fn generate(&self, rng: &mut Rng, scale: usize) -> Option<Generated> {
+        Some(generate::generate(rng, scale))
+    }
+}
+
+fn parse_step<'a>(input: &str, step: &'a str) -> Result<Step<'a>, ParseError> {
+    let error = |span: &str, message: &str| ParseError::new(Day15::DAY, input, span, message);
+    let operator_index = step
+        .find(['=', '-'])
+        .ok_or_else(|| error(step, "step should contain '=' or '-'"))?;
+    let (label, operation) = step.split_at(operator_index);
+    let label = label.trim_start_matches('\n'); // Newline characters are ignored
+    let operation = match operation.trim_end_matches('\n') {
+        "-" => Operation::Remove,
+        insert => {
+            let focal_length = &insert[1..];
+            match focal_length.parse() {
+                Ok(focal_length) if insert.starts_with('=') => Operation::Insert(focal_length),
+                _ => return Err(error(operation, "expected '-' or '=' and a focal length")),
+            }
+        }
+    };
+    Ok(Step {
+        text: step,
+        label,
+        operation,
+    })
+}
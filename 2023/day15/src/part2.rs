@@ -1,3 +1,6 @@
+use crate::{Operation, Step};
+use aoc_core::Answer;
+
 const MULTIPLY_CONSTANT: usize = 17;
 
 struct Lens {
@@ -5,9 +8,8 @@ struct Lens {
     focal_length: usize,
 }
 
-pub fn solve(input: &str) -> String {
+pub fn solve(lens_instructions: &[Step]) -> Answer {
     let mut lens_boxes: [Vec<Lens>; 256] = [(); 256].map(|_| Vec::new());
-    let lens_instructions: Vec<&str> = input.trim_end().split(',').collect();
 
     for step in lens_instructions {
         apply_lens_instructions_step(step, &mut lens_boxes);
@@ -19,7 +21,7 @@ pub fn solve(input: &str) -> String {
         .enumerate()
         .map(calculate_box_focusing_power)
         .sum::<usize>()
-        .to_string()
+        .into()
 }
 
 fn calculate_box_focusing_power(box_with_index: (usize, &Vec<Lens>)) -> usize {
@@ -31,32 +33,20 @@ fn calculate_box_focusing_power(box_with_index: (usize, &Vec<Lens>)) -> usize {
         .sum::<usize>()
 }
 
-fn apply_lens_instructions_step(step: &str, lens_boxes: &mut [Vec<Lens>; 256]) {
-    let label: String = extract_label(step).expect("label");
-    if step.contains('=') {
-        let box_number = hash(&label);
-        let focal_length: usize = step
-            .chars()
-            .last()
-            .expect("focal length")
-            .to_digit(10)
-            .expect("digit") as usize;
-        let lens = Lens {
-            label,
-            focal_length,
-        };
-        insert_to_lens_box(&mut lens_boxes[box_number], lens)
-    } else if step.contains('-') {
-        remove_from_lens_boxes(lens_boxes, &label);
+fn apply_lens_instructions_step(step: &Step, lens_boxes: &mut [Vec<Lens>; 256]) {
+    match step.operation {
+        Operation::Insert(focal_length) => {
+            let box_number = hash(step.label);
+            let lens = Lens {
+                label: step.label.to_string(),
+                focal_length,
+            };
+            insert_to_lens_box(&mut lens_boxes[box_number], lens)
+        }
+        Operation::Remove => remove_from_lens_boxes(lens_boxes, step.label),
     }
 }
 
-fn extract_label(input: &str) -> Option<String> {
-    input
-        .find(|c: char| c == '=' || c == '-')
-        .map(|idx| input[..idx].to_string())
-}
-
 fn remove_from_lens_boxes(lens_boxes: &mut [Vec<Lens>; 256], label: &str) {
     for lens_box in lens_boxes {
         if let Some(index) = lens_box.iter().position(|lens| lens.label == label) {
@@ -78,15 +68,3 @@ fn hash(input: &str) -> usize {
         (current_value + character as usize) * MULTIPLY_CONSTANT % 256
     })
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_solve() {
-        let input = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
-        let solution = "145";
-        assert_eq!(solution, solve(input));
-    }
-}
@@ -1,15 +1,5 @@
-pub fn solve(_input: &str) -> String {
-    todo!("Day XX - Part 1");
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
+use aoc_core::Answer;
 
-    #[test]
-    fn test_solve() {
-        let input = "input";
-        let solution = "solution";
-        assert_eq!(solution, solve(input));
-    }
+pub fn solve(_input: &str) -> Answer {
+    Answer::Unsolved
 }